@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Permission level for a `Reviewer`. Every reviewer is one of these today,
+/// and `approve`/`reject` don't currently distinguish between them -- the
+/// variant is recorded so future endpoints can gate on it without another
+/// migration, not because one already does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewerRole {
+    Moderator,
+    Admin,
+}
+
+/// A reviewer account. Credentials are stored as a bcrypt hash, never the
+/// plaintext password.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reviewer {
+    pub id: Uuid,
+    pub username: String,
+    pub password_hash: String,
+    pub role: ReviewerRole,
+}
+
+/// Errors from credential hashing/verification and reviewer lookup.
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("unknown reviewer '{0}'")]
+    UnknownReviewer(String),
+    #[error("invalid credentials for '{0}'")]
+    InvalidCredentials(String),
+    #[error("bcrypt error: {0}")]
+    Bcrypt(#[from] bcrypt::BcryptError),
+}
+
+impl Reviewer {
+    /// Create a reviewer account, hashing `password` at the given bcrypt
+    /// cost factor (see `ServiceConfig::auth_config`).
+    pub fn new(username: String, password: &str, role: ReviewerRole, cost: u32) -> Result<Self, AuthError> {
+        Ok(Self {
+            id: Uuid::now_v7(),
+            username,
+            password_hash: bcrypt::hash(password, cost)?,
+            role,
+        })
+    }
+
+    /// Verify `password` against this reviewer's stored hash.
+    pub fn verify_password(&self, password: &str) -> Result<bool, AuthError> {
+        Ok(bcrypt::verify(password, &self.password_hash)?)
+    }
+}
+
+/// In-memory directory of reviewer accounts, keyed by username.
+#[derive(Default)]
+pub struct ReviewerRegistry {
+    reviewers: HashMap<String, Reviewer>,
+}
+
+impl ReviewerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, reviewer: Reviewer) {
+        self.reviewers.insert(reviewer.username.clone(), reviewer);
+    }
+
+    /// Verify `username`/`password` and return the matching `Reviewer` on
+    /// success. This is the only way callers should obtain a `Reviewer` to
+    /// pass into `ProductStore::approve`/`reject`.
+    pub fn authenticate(&self, username: &str, password: &str) -> Result<&Reviewer, AuthError> {
+        let reviewer = self
+            .reviewers
+            .get(username)
+            .ok_or_else(|| AuthError::UnknownReviewer(username.to_string()))?;
+
+        if reviewer.verify_password(password)? {
+            Ok(reviewer)
+        } else {
+            Err(AuthError::InvalidCredentials(username.to_string()))
+        }
+    }
+}
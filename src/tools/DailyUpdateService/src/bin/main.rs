@@ -1,17 +1,27 @@
-use daily_update_service::{DailyUpdateServiceV2, config::ServiceConfig};
+use daily_update_service::{cli::Cli, DailyUpdateServiceV2, config::ServiceConfig};
 use anyhow::Result;
+use clap::Parser;
 use tracing::{info, error};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize tracing
     tracing_subscriber::fmt::init();
-    
-    info!("🚀 Starting DailyUpdateService Rust implementation...");
-    
+
+    let cli = Cli::parse();
+
     // Load configuration
-    let config = ServiceConfig::default();
-    
+    let mut config = ServiceConfig::default();
+    cli.apply_interval_override(&mut config)?;
+
+    // Dispatch a one-off operator command (run-once/status/approve/pending)
+    // instead of starting the daemon, if one was given.
+    if daily_update_service::cli::dispatch(&cli, config.clone()).await? {
+        return Ok(());
+    }
+
+    info!("🚀 Starting DailyUpdateService Rust implementation...");
+
     // Create service instance
     let service = DailyUpdateServiceV2::new(config);
     
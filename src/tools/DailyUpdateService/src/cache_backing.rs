@@ -0,0 +1,62 @@
+//! Optional durable tier for `CacheManager`, backed by a `sled` database
+//! rooted at `ServiceConfig::base_directory`, so a warm entry survives
+//! `perform_daily_cache_reset` and process restarts instead of forcing a
+//! full re-fetch through the Go binary.
+
+use anyhow::Result;
+use std::path::Path;
+
+/// A persistent store `CacheManager` consults on a hot-tier miss and
+/// write-throughs to on `insert`. Kept generic (rather than baked directly
+/// into `CacheManager`) so a non-`sled` backing can be swapped in for tests
+/// or a future deployment without touching the hot-tier eviction logic.
+pub trait CacheBacking: Send + Sync {
+    /// Look up `key`'s durably stored value, if any.
+    fn load(&self, key: &str) -> Result<Option<String>>;
+    /// Write `key` through to the durable tier, overwriting any prior value.
+    fn store(&self, key: &str, value: &str) -> Result<()>;
+    /// Remove `key` from the durable tier, if present.
+    fn delete(&self, key: &str) -> Result<()>;
+    /// Wipe the entire durable tier. Used by the rare explicit full-wipe
+    /// path, not by `perform_daily_cache_reset` (which preserves it).
+    fn clear(&self) -> Result<()>;
+}
+
+/// `sled`-backed `CacheBacking`, rooted at `<base_directory>/cache_backing`.
+pub struct SledCacheBacking {
+    tree: sled::Tree,
+}
+
+impl SledCacheBacking {
+    /// Open (creating if necessary) the durable cache tier under
+    /// `<base_directory>/cache_backing`.
+    pub fn open(base_directory: impl AsRef<Path>) -> Result<Self> {
+        let db = sled::open(base_directory.as_ref().join("cache_backing"))?;
+        let tree = db.open_tree("product_cache")?;
+        Ok(Self { tree })
+    }
+}
+
+impl CacheBacking for SledCacheBacking {
+    fn load(&self, key: &str) -> Result<Option<String>> {
+        match self.tree.get(key)? {
+            Some(bytes) => Ok(Some(String::from_utf8(bytes.to_vec())?)),
+            None => Ok(None),
+        }
+    }
+
+    fn store(&self, key: &str, value: &str) -> Result<()> {
+        self.tree.insert(key, value.as_bytes())?;
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        self.tree.remove(key)?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<()> {
+        self.tree.clear()?;
+        Ok(())
+    }
+}
@@ -3,19 +3,151 @@ use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use moka::future::Cache;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::RwLock;
 use tracing::{info, error, warn};
 
+use crate::cache_backing::{CacheBacking, SledCacheBacking};
+
+/// Internal outcome type threaded through `get_or_load`'s single-flight
+/// loader future so a "not found" result (don't cache) and a genuine loader
+/// failure (propagate, don't cache) are distinguishable from moka's side.
+#[derive(Debug, thiserror::Error)]
+enum CacheLoadError {
+    #[error("value not found by loader")]
+    NotFound,
+    #[error("loader failed: {0}")]
+    Loader(String),
+}
+
+/// Which entry `enforce_capacity` picks once `total_entries` exceeds
+/// `max_capacity`. Modeled on the policies `cached`'s `SizedCache` offers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvictionPolicy {
+    /// Evict the least-recently-accessed entry (a `get` or `insert` counts
+    /// as an access).
+    Lru,
+    /// Evict the least-frequently-accessed entry.
+    Lfu,
+    /// Evict the largest cached value first, so capacity is freed fastest
+    /// regardless of recency or frequency.
+    SizeWeighted,
+}
+
+/// Why an entry left the product cache, passed to every listener registered
+/// via `register_eviction_listener`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionCause {
+    /// `EvictionPolicy` evicted it because `max_capacity` was exceeded.
+    Capacity,
+    /// It passed its TTL, went untouched past `idle_seconds`, or a
+    /// `CanExpire` value said it had expired.
+    Expired,
+    /// It was removed via an explicit `remove` call.
+    Explicit,
+    /// `insert` overwrote it with a new value for the same key.
+    Replaced,
+    /// `enable_memory_pressure_monitor`'s background task evicted it to
+    /// bring `memory_usage_bytes` back under its configured limit, separate
+    /// from an ordinary `Capacity` eviction triggered by an `insert`.
+    Pressure,
+}
+
+type EvictionListener = Arc<dyn Fn(String, String, EvictionCause) + Send + Sync>;
+
+/// Lets a cached value (once deserialized) declare its own expiry,
+/// independent of the per-key TTL/idle timeout tracked by `insert`/
+/// `insert_with_ttl`. Mirrors `cached`'s `ExpiringValueCache` self-expiry
+/// hook -- a product record embedding an "approval deadline" can implement
+/// this to expire itself regardless of the cache's own TTL.
+pub trait CanExpire {
+    fn is_expired(&self) -> bool;
+}
+
+/// Per-key expiry bookkeeping backing lazy expiration on `get`. Not stored
+/// in `product_cache` itself since moka's value type there is the raw
+/// cached `String`.
+struct EntryExpiry {
+    expires_at: DateTime<Utc>,
+    last_accessed: DateTime<Utc>,
+}
+
+/// How often the background sweeper in `initialize` purges expired entries
+/// that haven't been touched by a `get` since expiring.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often `enable_memory_pressure_monitor`'s background task re-checks
+/// `memory_usage_bytes` against the configured soft/hard limits.
+const MEMORY_PRESSURE_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Fraction of `entry_count` evicted (coldest-first) on a single soft-limit
+/// poll. Repeated polls keep shaving this fraction off until usage drops
+/// back under the soft limit, rather than trying to land exactly on it in
+/// one pass.
+const MEMORY_PRESSURE_SOFT_EVICTION_FRACTION: f64 = 0.1;
+
+/// One product entry as written by `snapshot_to` / read by `restore_from`.
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotEntry {
+    key: String,
+    value: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// On-disk format written by `snapshot_to` and read by `restore_from`.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheSnapshot {
+    product_entries: Vec<SnapshotEntry>,
+    admin_entries: Vec<(String, String)>,
+    stats: CacheStats,
+}
+
 /// Cache statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheStats {
     pub total_entries: u64,
     pub hit_count: u64,
     pub miss_count: u64,
+    /// Incremented once per entry that leaves the product cache or is
+    /// overwritten, for any `EvictionCause` -- capacity, expiry, an explicit
+    /// `remove`, or a `Replaced` `insert`. Every cause except `Replaced`
+    /// (which leaves the entry count unchanged) also decrements
+    /// `total_entries` in the same step, so, unlike moka's own (unused)
+    /// eviction listener, this can never drift from the cache's actual
+    /// occupancy.
     pub eviction_count: u64,
+    /// Slice of `eviction_count` attributable to `EvictionCause::Capacity`
+    /// (an `enforce_capacity` eviction once `max_capacity`/the memory budget
+    /// was exceeded).
+    pub capacity_eviction_count: u64,
+    /// Slice of `eviction_count` attributable to `EvictionCause::Expired`
+    /// (a passed TTL, an idle timeout, or a `CanExpire` value).
+    pub expired_eviction_count: u64,
+    /// Slice of `eviction_count` attributable to `EvictionCause::Explicit`
+    /// (a caller's `remove`).
+    pub explicit_eviction_count: u64,
+    /// Slice of `eviction_count` attributable to `EvictionCause::Replaced`
+    /// (an `insert` overwriting an existing key).
+    pub replaced_eviction_count: u64,
+    pub expired_count: u64,
+    /// Running total of `key.len() + value.len()` across every entry
+    /// currently in `product_cache`, kept exact by `insert_hot`/`evict_key`/
+    /// the expiry sweeper rather than estimated from `total_entries`.
     pub memory_usage_bytes: u64,
     pub last_reset_time: DateTime<Utc>,
+    /// Hot-tier misses resolved by the durable backing tier instead of a
+    /// true miss. Non-zero here is the warm-restart path working.
+    pub backing_hit_count: u64,
+    /// Misses on both the hot tier and the durable backing tier.
+    pub backing_miss_count: u64,
+    /// Entries evicted by `enable_memory_pressure_monitor`'s background
+    /// task, separate from `eviction_count` so an operator can tell a
+    /// pressure-triggered shrink apart from ordinary `max_capacity`
+    /// enforcement. Always `0` if the monitor was never enabled.
+    pub pressure_eviction_count: u64,
 }
 
 /// Cache Manager - Handles caching operations for the DailyUpdateService
@@ -37,63 +169,550 @@ pub struct CacheManager {
     hit_count: Arc<DashMap<String, u64>>,
     miss_count: Arc<DashMap<String, u64>>,
     eviction_count: Arc<DashMap<String, u64>>,
+    // Per-`EvictionCause` breakdown of `eviction_count`, kept in lockstep by
+    // `evict_key` so `get_cache_stats` can report *why* entries left, not
+    // just how many.
+    capacity_eviction_count: Arc<DashMap<String, u64>>,
+    expired_eviction_count: Arc<DashMap<String, u64>>,
+    explicit_eviction_count: Arc<DashMap<String, u64>>,
+    replaced_eviction_count: Arc<DashMap<String, u64>>,
     entry_count: Arc<std::sync::atomic::AtomicU64>,
-    
+    // `key.len() + value.len()` summed across every entry in `product_cache`,
+    // updated in lockstep with `entry_count` everywhere it changes.
+    memory_usage_bytes: Arc<std::sync::atomic::AtomicU64>,
+
     // Last reset time
     last_reset_time: Arc<DashMap<String, DateTime<Utc>>>,
-    
+
     // Cache configuration
     max_capacity: u64,
+    // Optional additional bound alongside `max_capacity`: `enforce_capacity`
+    // also evicts while `memory_usage_bytes` exceeds this, so a cache of a
+    // few huge product blobs can't blow past a memory budget even while
+    // under `max_capacity` on entry count alone. `None` (the default) means
+    // `max_capacity` is the only bound, as before `with_memory_budget`.
+    max_memory_bytes: Option<u64>,
     ttl_seconds: u64,
+    idle_seconds: u64,
+
+    // `max_capacity` enforcement: which entry `enforce_capacity` picks, and
+    // the bookkeeping each policy needs. moka's own `max_capacity` is left
+    // unset on `product_cache` (see `new`) so these -- not moka's internal
+    // TinyLFU -- are the sole authority over how many entries are kept.
+    eviction_policy: EvictionPolicy,
+    eviction_listeners: Arc<RwLock<Vec<EvictionListener>>>,
+    lru_order: Arc<RwLock<VecDeque<String>>>,
+    access_counts: Arc<DashMap<String, u64>>,
+
+    // Per-key TTL/idle enforcement. moka's own `time_to_live`/`time_to_idle`
+    // are left unset on `product_cache` for the same reason `max_capacity`
+    // is: `get`'s lazy expiration below (and the sweeper spawned from
+    // `initialize`) are the sole authority, so `ttl_seconds`/`idle_seconds`
+    // actually apply per entry instead of being ignored. A moka `Expiry`
+    // hook was tried here once (driving TTL natively off this map) and
+    // reverted: moka's own clock would then expire an entry out from under
+    // `get`/`enforce_capacity` before `evict_key` ever ran, leaving
+    // `entry_count`/`eviction_count` silently out of sync with what moka
+    // itself had already dropped.
+    expiry: Arc<DashMap<String, EntryExpiry>>,
+    expired_count: Arc<DashMap<String, u64>>,
+
+    // Optional durable tier consulted on a hot-tier miss and written
+    // through to on insert, so a warm entry survives
+    // `perform_daily_cache_reset` and process restarts. Opened inside
+    // `initialize` (it needs `ServiceConfig::base_directory`, which isn't
+    // known at construction time) -- mirrors how `DailyUpdateServiceV2`
+    // itself defers opening `PendingUpdateQueue` to its own `initialize`.
+    backing: Arc<RwLock<Option<Arc<dyn CacheBacking>>>>,
+    backing_hit_count: Arc<DashMap<String, u64>>,
+    backing_miss_count: Arc<DashMap<String, u64>>,
+
+    // Entries evicted by `enable_memory_pressure_monitor`'s background task.
+    pressure_eviction_count: Arc<DashMap<String, u64>>,
 }
 
 impl CacheManager {
     /// Create a new CacheManager instance
     pub fn new() -> Self {
+        let eviction_listeners: Arc<RwLock<Vec<EvictionListener>>> = Arc::new(RwLock::new(Vec::new()));
+
+        // moka's own TTL/TTI and `max_capacity` are deliberately left unset:
+        // `insert`/`get`/`enforce_capacity` below own expiry and capacity
+        // entirely, so `ttl_seconds`/`idle_seconds`/`max_capacity` actually
+        // apply per entry instead of moka silently enforcing its own
+        // defaults underneath them. For the same reason, `product_cache`
+        // registers no moka `eviction_listener` either -- `evict_key` below
+        // is the sole place entries leave the cache, and it already reports
+        // every `EvictionCause` to `eviction_listeners` and keeps
+        // `eviction_count`/`entry_count` exact; a moka-side listener would
+        // just double-count the evictions `evict_key` already accounts for.
         Self {
-            product_cache: Arc::new(
-                Cache::builder()
-                    .max_capacity(10_000)
-                    .time_to_live(Duration::from_secs(3600)) // 1 hour TTL
-                    .time_to_idle(Duration::from_secs(1800)) // 30 minutes idle
-                    .build(),
-            ),
+            product_cache: Arc::new(Cache::builder().build()),
             admin_cache: Arc::new(DashMap::new()),
             hit_count: Arc::new(DashMap::new()),
             miss_count: Arc::new(DashMap::new()),
             eviction_count: Arc::new(DashMap::new()),
+            capacity_eviction_count: Arc::new(DashMap::new()),
+            expired_eviction_count: Arc::new(DashMap::new()),
+            explicit_eviction_count: Arc::new(DashMap::new()),
+            replaced_eviction_count: Arc::new(DashMap::new()),
             entry_count: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            memory_usage_bytes: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             last_reset_time: Arc::new(DashMap::new()),
             max_capacity: 10_000,
+            max_memory_bytes: None,
             ttl_seconds: 3600,
+            idle_seconds: 1800,
+            eviction_policy: EvictionPolicy::Lru,
+            eviction_listeners,
+            lru_order: Arc::new(RwLock::new(VecDeque::new())),
+            access_counts: Arc::new(DashMap::new()),
+            expiry: Arc::new(DashMap::new()),
+            expired_count: Arc::new(DashMap::new()),
+            backing: Arc::new(RwLock::new(None)),
+            backing_hit_count: Arc::new(DashMap::new()),
+            backing_miss_count: Arc::new(DashMap::new()),
+            pressure_eviction_count: Arc::new(DashMap::new()),
         }
     }
-    
-    /// Initialize the cache manager
-    pub async fn initialize(&self) -> Result<bool> {
+
+    /// Override the bound `enforce_capacity` evicts against (default:
+    /// `10_000`, matching the old hardcoded moka limit this replaces).
+    pub fn with_max_capacity(mut self, max_capacity: u64) -> Self {
+        self.max_capacity = max_capacity;
+        self
+    }
+
+    /// Size the cache to a byte budget instead of (well, alongside) an entry
+    /// count: `enforce_capacity` will also evict, per `eviction_policy`,
+    /// while `memory_usage_bytes` exceeds `bytes`. Leaves `max_capacity` at
+    /// its default, so an entry-count runaway (many tiny values) still can't
+    /// grow `product_cache` unbounded even if it never reaches `bytes`.
+    /// Also sets `ttl_seconds`, since a memory-budgeted cache is usually
+    /// sized together with how long it's allowed to hold onto an entry.
+    pub fn with_memory_budget(mut self, bytes: u64, ttl_seconds: u64) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self.ttl_seconds = ttl_seconds;
+        self
+    }
+
+    /// Override which entry an over-capacity `insert` evicts (default:
+    /// `Lru`).
+    pub fn with_eviction_policy(mut self, policy: EvictionPolicy) -> Self {
+        self.eviction_policy = policy;
+        self
+    }
+
+    /// Override the default per-entry TTL that plain `insert` applies
+    /// (default: `3600`, matching the old hardcoded moka TTL this
+    /// replaces). Use `insert_with_ttl` to override it for one entry.
+    pub fn with_ttl_seconds(mut self, ttl_seconds: u64) -> Self {
+        self.ttl_seconds = ttl_seconds;
+        self
+    }
+
+    /// Override how long an entry may go untouched by `get` before it's
+    /// treated as expired (default: `1800`, matching the old hardcoded
+    /// moka idle timeout this replaces).
+    pub fn with_idle_seconds(mut self, idle_seconds: u64) -> Self {
+        self.idle_seconds = idle_seconds;
+        self
+    }
+
+    /// Register a callback invoked whenever an entry leaves the product
+    /// cache, with the reason it left. Multiple listeners may be registered;
+    /// each is called for every eviction, in registration order.
+    pub async fn register_eviction_listener<F>(&self, listener: F)
+    where
+        F: Fn(String, String, EvictionCause) + Send + Sync + 'static,
+    {
+        self.eviction_listeners.write().await.push(Arc::new(listener));
+    }
+
+    async fn notify_eviction(&self, key: String, value: String, cause: EvictionCause) {
+        for listener in self.eviction_listeners.read().await.iter() {
+            listener(key.clone(), value.clone(), cause);
+        }
+    }
+
+    /// Remove `key` from the product cache and every bookkeeping structure,
+    /// reporting `cause` to registered listeners. Assumes `key` is present.
+    async fn evict_key(&self, key: String, value: String, cause: EvictionCause) {
+        self.product_cache.invalidate(&key).await;
+        self.entry_count.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+        self.memory_usage_bytes.fetch_sub(
+            (key.len() + value.len()) as u64,
+            std::sync::atomic::Ordering::Relaxed,
+        );
+        if let Some(mut count) = self.eviction_count.get_mut("product_cache") {
+            *count += 1;
+        }
+        let by_cause = match cause {
+            EvictionCause::Capacity => &self.capacity_eviction_count,
+            EvictionCause::Expired => &self.expired_eviction_count,
+            EvictionCause::Explicit => &self.explicit_eviction_count,
+            EvictionCause::Replaced => &self.replaced_eviction_count,
+            // Already broken out separately as `pressure_eviction_count`.
+            EvictionCause::Pressure => &self.pressure_eviction_count,
+        };
+        if let Some(mut count) = by_cause.get_mut("product_cache") {
+            *count += 1;
+        } else {
+            by_cause.insert("product_cache".to_string(), 1);
+        }
+        self.access_counts.remove(&key);
+        self.expiry.remove(&key);
+        {
+            let mut order = self.lru_order.write().await;
+            order.retain(|k| k != &key);
+        }
+        self.notify_eviction(key, value, cause).await;
+    }
+
+    /// `true` if `key`'s entry has a recorded expiry and is past its
+    /// `expires_at` or has gone untouched longer than `idle_seconds`.
+    fn is_expired(&self, key: &str) -> bool {
+        self.expiry
+            .get(key)
+            .map(|entry| {
+                let now = Utc::now();
+                now > entry.expires_at
+                    || now.signed_duration_since(entry.last_accessed).num_milliseconds() as u64
+                        > self.idle_seconds * 1000
+            })
+            .unwrap_or(false)
+    }
+
+    /// Touch `key` as accessed: move it to the back of the LRU order and
+    /// bump its LFU access count.
+    async fn record_access(&self, key: &str) {
+        self.access_counts.entry(key.to_string()).and_modify(|c| *c += 1).or_insert(1);
+        let mut order = self.lru_order.write().await;
+        order.retain(|k| k != key);
+        order.push_back(key.to_string());
+    }
+
+    /// `true` while `entry_count` exceeds `max_capacity`, or (if
+    /// `with_memory_budget` was used) `memory_usage_bytes` exceeds
+    /// `max_memory_bytes`.
+    fn is_over_capacity(&self) -> bool {
+        self.entry_count.load(std::sync::atomic::Ordering::Relaxed) > self.max_capacity
+            || self
+                .max_memory_bytes
+                .is_some_and(|budget| self.memory_usage_bytes.load(std::sync::atomic::Ordering::Relaxed) > budget)
+    }
+
+    /// Evict entries, per `eviction_policy`, until neither bound checked by
+    /// `is_over_capacity` is exceeded.
+    async fn enforce_capacity(&self) {
+        while self.is_over_capacity() {
+            let victim = match self.eviction_policy {
+                EvictionPolicy::Lru => self.lru_order.read().await.front().cloned(),
+                // Ties on access count go to the least-recently-used entry,
+                // so a just-inserted key isn't evicted ahead of an equally
+                // cold older one.
+                EvictionPolicy::Lfu => self
+                    .lru_order
+                    .read()
+                    .await
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(position, key)| {
+                        (self.access_counts.get(key.as_str()).map_or(0, |count| *count), *position)
+                    })
+                    .map(|(_, key)| key.clone()),
+                EvictionPolicy::SizeWeighted => {
+                    let mut largest: Option<(String, usize)> = None;
+                    for (key, value) in self.product_cache.iter() {
+                        let size = value.len();
+                        if largest.as_ref().map_or(true, |(_, largest_size)| size > *largest_size) {
+                            largest = Some(((*key).clone(), size));
+                        }
+                    }
+                    largest.map(|(key, _)| key)
+                }
+            };
+
+            let Some(key) = victim else { break };
+            let Some(value) = self.product_cache.get(&key).await else { break };
+            self.evict_key(key, value, EvictionCause::Capacity).await;
+        }
+    }
+
+    /// Start a background task that watches `memory_usage_bytes` and
+    /// proactively shrinks the product cache to avoid an OOM, independent of
+    /// `enforce_capacity`'s per-insert enforcement of `max_capacity`/
+    /// `max_memory_bytes`. That per-insert path only ever fires on an
+    /// `insert`; this is a periodic safety net for usage drifting over a
+    /// limit some other way -- e.g. a burst of `insert`s each individually
+    /// under `max_memory_bytes` before `enforce_capacity` runs, or a budget
+    /// lowered after the cache was already full.
+    ///
+    /// Every `MEMORY_PRESSURE_POLL_INTERVAL`: crossing `soft_limit_bytes`
+    /// evicts a `MEMORY_PRESSURE_SOFT_EVICTION_FRACTION` slice of
+    /// `entry_count`, coldest-first by `lru_order` regardless of
+    /// `eviction_policy` -- effectively lowering the cache's capacity on the
+    /// fly. Crossing `hard_limit_bytes` instead treats the situation as an
+    /// emergency and does a full `clear()` of the product cache (the admin
+    /// cache, per its own doc comment, is only ever reset on an outage, so
+    /// it's left untouched). Either way the batch is recorded in
+    /// `pressure_eviction_count`, separate from ordinary `Capacity`
+    /// evictions, so operators can see when a pressure-triggered shrink
+    /// happened.
+    ///
+    /// Call at most once per `CacheManager`; a second call spawns a second,
+    /// independent monitor task racing the first.
+    pub async fn enable_memory_pressure_monitor(&self, soft_limit_bytes: u64, hard_limit_bytes: u64) {
+        let product_cache = self.product_cache.clone();
+        let entry_count = self.entry_count.clone();
+        let memory_usage_bytes = self.memory_usage_bytes.clone();
+        let eviction_count = self.eviction_count.clone();
+        let pressure_eviction_count = self.pressure_eviction_count.clone();
+        let access_counts = self.access_counts.clone();
+        let lru_order = self.lru_order.clone();
+        let eviction_listeners = self.eviction_listeners.clone();
+        let expiry = self.expiry.clone();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(MEMORY_PRESSURE_POLL_INTERVAL).await;
+
+                let usage = memory_usage_bytes.load(std::sync::atomic::Ordering::Relaxed);
+                if usage <= soft_limit_bytes {
+                    continue;
+                }
+
+                if usage > hard_limit_bytes {
+                    let batch = entry_count.swap(0, std::sync::atomic::Ordering::Relaxed);
+                    if batch == 0 {
+                        continue;
+                    }
+                    product_cache.invalidate_all();
+                    memory_usage_bytes.store(0, std::sync::atomic::Ordering::Relaxed);
+                    access_counts.clear();
+                    expiry.clear();
+                    lru_order.write().await.clear();
+                    if let Some(mut count) = eviction_count.get_mut("product_cache") {
+                        *count += batch;
+                    }
+                    if let Some(mut count) = pressure_eviction_count.get_mut("product_cache") {
+                        *count += batch;
+                    }
+                    warn!(
+                        "memory pressure hard limit breached ({} bytes > {}); emergency-cleared {} entries",
+                        usage, hard_limit_bytes, batch
+                    );
+                    continue;
+                }
+
+                let total_entries = entry_count.load(std::sync::atomic::Ordering::Relaxed);
+                let quota = ((total_entries as f64) * MEMORY_PRESSURE_SOFT_EVICTION_FRACTION).ceil() as u64;
+                let mut evicted = 0u64;
+
+                while evicted < quota.max(1) {
+                    let Some(key) = lru_order.read().await.front().cloned() else { break };
+                    let Some(value) = product_cache.get(&key).await else {
+                        lru_order.write().await.retain(|k| k != &key);
+                        continue;
+                    };
+
+                    product_cache.invalidate(&key).await;
+                    entry_count.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+                    memory_usage_bytes.fetch_sub(
+                        (key.len() + value.len()) as u64,
+                        std::sync::atomic::Ordering::Relaxed,
+                    );
+                    if let Some(mut count) = eviction_count.get_mut("product_cache") {
+                        *count += 1;
+                    }
+                    access_counts.remove(&key);
+                    expiry.remove(&key);
+                    {
+                        let mut order = lru_order.write().await;
+                        order.retain(|k| k != &key);
+                    }
+                    for listener in eviction_listeners.read().await.iter() {
+                        listener(key.clone(), value.clone(), EvictionCause::Pressure);
+                    }
+                    evicted += 1;
+                }
+
+                if evicted > 0 {
+                    if let Some(mut count) = pressure_eviction_count.get_mut("product_cache") {
+                        *count += evicted;
+                    }
+                    warn!(
+                        "memory pressure soft limit breached ({} bytes > {}); evicted {} coldest entries",
+                        usage, soft_limit_bytes, evicted
+                    );
+                }
+            }
+        });
+    }
+
+    /// Initialize the cache manager: open the durable backing tier under
+    /// `<base_directory>/cache_backing` and spawn the background sweeper
+    /// that purges expired entries between `get`s so memory doesn't grow
+    /// unbounded from keys nobody looks up again.
+    pub async fn initialize(&self, base_directory: impl AsRef<Path>) -> Result<bool> {
         info!("🔧 Initializing CacheManager...");
-        
+
+        let base_directory = base_directory.as_ref().to_path_buf();
+        let backing = SledCacheBacking::open(&base_directory)?;
+        *self.backing.write().await = Some(Arc::new(backing) as Arc<dyn CacheBacking>);
+
         // Initialize statistics
         self.hit_count.insert("product_cache".to_string(), 0);
         self.miss_count.insert("product_cache".to_string(), 0);
         self.eviction_count.insert("product_cache".to_string(), 0);
+        self.capacity_eviction_count.insert("product_cache".to_string(), 0);
+        self.expired_eviction_count.insert("product_cache".to_string(), 0);
+        self.explicit_eviction_count.insert("product_cache".to_string(), 0);
+        self.replaced_eviction_count.insert("product_cache".to_string(), 0);
+        self.expired_count.insert("product_cache".to_string(), 0);
+        self.backing_hit_count.insert("product_cache".to_string(), 0);
+        self.backing_miss_count.insert("product_cache".to_string(), 0);
+        self.pressure_eviction_count.insert("product_cache".to_string(), 0);
         self.last_reset_time.insert("product_cache".to_string(), Utc::now());
-        
+
+        // Repopulate from a prior `snapshot_to` export, if one exists, so a
+        // process restart doesn't cold-start the whole product cache. Best
+        // effort: a corrupt or unreadable snapshot is logged and skipped
+        // rather than failing `initialize` outright.
+        let snapshot_path = base_directory.join("cache_snapshot.postcard");
+        if snapshot_path.exists() {
+            if let Err(e) = self.restore_from(&snapshot_path).await {
+                warn!("failed to restore cache snapshot from {:?}: {}", snapshot_path, e);
+            }
+        }
+
+        let product_cache = self.product_cache.clone();
+        let expiry = self.expiry.clone();
+        let entry_count = self.entry_count.clone();
+        let memory_usage_bytes = self.memory_usage_bytes.clone();
+        let eviction_count = self.eviction_count.clone();
+        let expired_count = self.expired_count.clone();
+        let expired_eviction_count = self.expired_eviction_count.clone();
+        let access_counts = self.access_counts.clone();
+        let lru_order = self.lru_order.clone();
+        let eviction_listeners = self.eviction_listeners.clone();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(SWEEP_INTERVAL).await;
+
+                let now = Utc::now();
+                let expired_keys: Vec<String> = expiry
+                    .iter()
+                    .filter(|entry| now > entry.expires_at)
+                    .map(|entry| entry.key().clone())
+                    .collect();
+
+                for key in expired_keys {
+                    expiry.remove(&key);
+                    let Some(value) = product_cache.get(&key).await else { continue };
+                    product_cache.invalidate(&key).await;
+                    entry_count.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+                    memory_usage_bytes.fetch_sub(
+                        (key.len() + value.len()) as u64,
+                        std::sync::atomic::Ordering::Relaxed,
+                    );
+                    if let Some(mut count) = eviction_count.get_mut("product_cache") {
+                        *count += 1;
+                    }
+                    if let Some(mut count) = expired_count.get_mut("product_cache") {
+                        *count += 1;
+                    }
+                    if let Some(mut count) = expired_eviction_count.get_mut("product_cache") {
+                        *count += 1;
+                    }
+                    access_counts.remove(&key);
+                    {
+                        let mut order = lru_order.write().await;
+                        order.retain(|k| k != &key);
+                    }
+                    for listener in eviction_listeners.read().await.iter() {
+                        listener(key.clone(), value.clone(), EvictionCause::Expired);
+                    }
+                }
+            }
+        });
+
         info!("✅ CacheManager initialized successfully");
         Ok(true)
     }
-    
-    /// Get a value from the product cache
+
+    /// `key`'s durably stored value, if a backing tier is configured and
+    /// has one.
+    async fn load_from_backing(&self, key: &str) -> Option<String> {
+        let backing = self.backing.read().await.clone()?;
+        match backing.load(key) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("failed to read cache entry from durable backing: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Write `key`/`value` through to the backing tier, if one is
+    /// configured. Best-effort: a write failure is logged, not propagated,
+    /// since the hot-tier insert it follows has already succeeded.
+    async fn store_to_backing(&self, key: &str, value: &str) {
+        let Some(backing) = self.backing.read().await.clone() else { return };
+        if let Err(e) = backing.store(key, value) {
+            warn!("failed to write cache entry through to durable backing: {}", e);
+        }
+    }
+
+    /// Get a value from the product cache. An entry past its `expires_at`
+    /// or untouched for longer than `idle_seconds` is treated as a miss and
+    /// evicted, even if the sweeper hasn't reached it yet. A hot-tier miss
+    /// consults the durable backing tier (if configured) before declaring a
+    /// true miss, warming the hot tier on success so repeated lookups don't
+    /// keep round-tripping to disk.
     pub async fn get(&self, key: &str) -> Option<String> {
+        if self.is_expired(key) {
+            if let Some(value) = self.product_cache.get(key).await {
+                self.evict_key(key.to_string(), value, EvictionCause::Expired).await;
+            }
+            if let Some(mut count) = self.expired_count.get_mut("product_cache") {
+                *count += 1;
+            }
+            if let Some(mut count) = self.miss_count.get_mut("product_cache") {
+                *count += 1;
+            }
+            return None;
+        }
+
         match self.product_cache.get(key).await {
             Some(value) => {
                 // Update hit count
                 if let Some(mut count) = self.hit_count.get_mut("product_cache") {
                     *count += 1;
                 }
+                self.record_access(key).await;
+                if let Some(mut entry) = self.expiry.get_mut(key) {
+                    entry.last_accessed = Utc::now();
+                }
                 Some(value)
             }
             None => {
+                if let Some(value) = self.load_from_backing(key).await {
+                    if let Some(mut count) = self.backing_hit_count.get_mut("product_cache") {
+                        *count += 1;
+                    }
+                    if let Some(mut count) = self.hit_count.get_mut("product_cache") {
+                        *count += 1;
+                    }
+                    self.insert_hot(key.to_string(), value.clone(), Duration::from_secs(self.ttl_seconds))
+                        .await;
+                    return Some(value);
+                }
+
+                if let Some(mut count) = self.backing_miss_count.get_mut("product_cache") {
+                    *count += 1;
+                }
                 // Update miss count
                 if let Some(mut count) = self.miss_count.get_mut("product_cache") {
                     *count += 1;
@@ -102,44 +721,302 @@ impl CacheManager {
             }
         }
     }
-    
-    /// Insert a value into the product cache
+
+    /// Like `get`, but additionally parses the cached value as `V` and
+    /// evicts it as expired if `V::is_expired()` says so, regardless of the
+    /// entry's own TTL/idle timeout. Values that don't parse as `V` are
+    /// returned as-is: `CanExpire` is opt-in per caller, not a property of
+    /// the cache itself.
+    pub async fn get_checked<V>(&self, key: &str) -> Option<String>
+    where
+        V: CanExpire + serde::de::DeserializeOwned,
+    {
+        let value = self.get(key).await?;
+
+        if let Ok(parsed) = serde_json::from_str::<V>(&value) {
+            if parsed.is_expired() {
+                self.evict_key(key.to_string(), value, EvictionCause::Expired).await;
+                if let Some(mut count) = self.expired_count.get_mut("product_cache") {
+                    *count += 1;
+                }
+                return None;
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Insert a value into the product cache with the default
+    /// `ttl_seconds`, evicting an entry per `eviction_policy` if this
+    /// pushes `total_entries` past `max_capacity`.
     pub async fn insert(&self, key: String, value: String) {
-        let is_new_key = !self.product_cache.contains_key(&key);
-        self.product_cache.insert(key, value).await;
-        // Increment entry count if this is a new key
-        if is_new_key {
-            self.entry_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.insert_with_ttl(key, value, Duration::from_secs(self.ttl_seconds)).await;
+    }
+
+    /// Insert a value into the product cache with a per-entry TTL,
+    /// overriding the default `ttl_seconds` for this key. Otherwise
+    /// behaves exactly like `insert`. Write-throughs to the backing tier
+    /// (if configured), so the entry survives `perform_daily_cache_reset`
+    /// and process restarts.
+    ///
+    /// This is the cache's heterogeneous-lifetime mechanism: `expiry` (see
+    /// `EntryExpiry`) tracks `expires_at` per key rather than relying on
+    /// moka's own `time_to_live`, so a volatile entry and a stable one can
+    /// share `product_cache` with different lifetimes, and `get`/`is_expired`
+    /// already treat a past-`expires_at` entry as a miss before moka's own
+    /// (unset) TTL would ever apply.
+    pub async fn insert_with_ttl(&self, key: String, value: String, ttl: Duration) {
+        self.insert_hot(key.clone(), value.clone(), ttl).await;
+        self.store_to_backing(&key, &value).await;
+    }
+
+    /// Hot-tier-only insert, used both by `insert_with_ttl` (which also
+    /// write-throughs to the backing tier) and by `get` to warm the hot
+    /// tier from a backing-tier hit without re-writing back to the backing
+    /// tier it just came from.
+    async fn insert_hot(&self, key: String, value: String, ttl: Duration) {
+        let previous = self.product_cache.get(&key).await;
+        self.product_cache.insert(key.clone(), value.clone()).await;
+        self.record_access(&key).await;
+
+        let now = Utc::now();
+        self.expiry.insert(
+            key.clone(),
+            EntryExpiry {
+                expires_at: now + chrono::Duration::from_std(ttl).unwrap_or_else(|_| chrono::Duration::zero()),
+                last_accessed: now,
+            },
+        );
+
+        match previous {
+            Some(previous_value) => {
+                self.memory_usage_bytes.fetch_sub(
+                    (key.len() + previous_value.len()) as u64,
+                    std::sync::atomic::Ordering::Relaxed,
+                );
+                self.memory_usage_bytes.fetch_add(
+                    (key.len() + value.len()) as u64,
+                    std::sync::atomic::Ordering::Relaxed,
+                );
+                if let Some(mut count) = self.eviction_count.get_mut("product_cache") {
+                    *count += 1;
+                }
+                if let Some(mut count) = self.replaced_eviction_count.get_mut("product_cache") {
+                    *count += 1;
+                }
+                self.notify_eviction(key, previous_value, EvictionCause::Replaced).await;
+            }
+            None => {
+                self.entry_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                self.memory_usage_bytes.fetch_add(
+                    (key.len() + value.len()) as u64,
+                    std::sync::atomic::Ordering::Relaxed,
+                );
+                self.enforce_capacity().await;
+            }
         }
     }
     
+    /// Read-through lookup: return the cached value for `key`, or run
+    /// `loader` on a miss and cache its result.
+    ///
+    /// Concurrent callers racing on the same missing `key` single-flight the
+    /// load -- only the first caller's `loader` future actually runs, and
+    /// every other caller awaits that same in-flight result instead of
+    /// duplicating the fetch, via moka's own `entry_by_ref`/
+    /// `or_try_insert_with`. `loader` returning `Ok(None)` means "not
+    /// found": nothing is cached and this returns `Ok(None)`. `loader`
+    /// returning `Err` propagates without caching anything.
+    ///
+    /// The initial `get` call above still records a hit/miss per caller --
+    /// every racer genuinely misses, so `miss_count` is deliberately bumped
+    /// once per racer, not deduplicated down to one. What *is* deduplicated
+    /// is `entry_count`/`memory_usage_bytes`/`enforce_capacity`: those only
+    /// run for whichever caller's `Entry::is_fresh()` comes back `true` --
+    /// the one that actually populated the cache -- so N racing callers
+    /// account for one entry, not N, even though moka doesn't resolve who
+    /// that is until after the load completes.
+    pub async fn get_or_load<F, Fut>(&self, key: String, loader: F) -> Result<Option<String>>
+    where
+        F: FnOnce(String) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = Result<Option<String>>> + Send + 'static,
+    {
+        if let Some(value) = self.get(&key).await {
+            return Ok(Some(value));
+        }
+
+        let key_for_bookkeeping = key.clone();
+        let key_for_loader = key.clone();
+
+        match self
+            .product_cache
+            .entry_by_ref(&key)
+            .or_try_insert_with(async move {
+                match loader(key_for_loader).await {
+                    Ok(Some(value)) => Ok(value),
+                    Ok(None) => Err(CacheLoadError::NotFound),
+                    Err(e) => Err(CacheLoadError::Loader(e.to_string())),
+                }
+            })
+            .await
+        {
+            Ok(entry) => {
+                let is_fresh = entry.is_fresh();
+                let value = entry.into_value();
+                self.record_access(&key_for_bookkeeping).await;
+                let now = Utc::now();
+                self.expiry.insert(
+                    key_for_bookkeeping.clone(),
+                    EntryExpiry {
+                        expires_at: now + chrono::Duration::seconds(self.ttl_seconds as i64),
+                        last_accessed: now,
+                    },
+                );
+                if is_fresh {
+                    self.entry_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    self.memory_usage_bytes.fetch_add(
+                        (key_for_bookkeeping.len() + value.len()) as u64,
+                        std::sync::atomic::Ordering::Relaxed,
+                    );
+                    self.enforce_capacity().await;
+                }
+                self.store_to_backing(&key_for_bookkeeping, &value).await;
+                Ok(Some(value))
+            }
+            Err(err) => match err.as_ref() {
+                CacheLoadError::NotFound => Ok(None),
+                CacheLoadError::Loader(message) => Err(anyhow::anyhow!(message.clone())),
+            },
+        }
+    }
+
     /// Insert multiple values into the product cache
     pub async fn insert_batch(&self, entries: Vec<(String, String)>) {
         for (key, value) in entries {
-            let is_new_key = !self.product_cache.contains_key(&key);
-            self.product_cache.insert(key, value).await;
-            if is_new_key {
-                self.entry_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-            }
+            self.insert(key, value).await;
         }
     }
-    
-    /// Remove a value from the product cache
+
+    /// Remove a value from the product cache, and from the backing tier if
+    /// one is configured.
     pub async fn remove(&self, key: &str) {
-        let had_key = self.product_cache.contains_key(key);
-        self.product_cache.invalidate(key).await;
-        if had_key {
-            self.entry_count.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+        if let Some(value) = self.product_cache.get(key).await {
+            self.evict_key(key.to_string(), value, EvictionCause::Explicit).await;
+        }
+        if let Some(backing) = self.backing.read().await.clone() {
+            if let Err(e) = backing.delete(key) {
+                warn!("failed to delete cache entry from durable backing: {}", e);
+            }
         }
     }
-    
-    /// Clear all product cache entries
+
+    /// Flush moka's deferred write/eviction housekeeping on `product_cache`
+    /// so a caller reading `entry_count`/`eviction_count` (via
+    /// `get_cache_stats`) or relying on an eviction listener having already
+    /// fired sees fully consistent state, instead of racing moka's own
+    /// asynchronous maintenance. Our own bookkeeping (`entry_count`,
+    /// `memory_usage_bytes`, `eviction_count`, `lru_order`, ...) is already
+    /// updated synchronously in `evict_key`/`insert_hot`/`enforce_capacity`
+    /// -- this only matters for state moka itself defers internally, e.g.
+    /// `product_cache.iter()`'s view of what's actually present.
+    pub async fn sync(&self) {
+        self.product_cache.run_pending_tasks().await;
+    }
+
+    /// Clear all product cache entries. Leaves the backing tier untouched,
+    /// so a warm restart can still serve out of it -- use `clear_backing`
+    /// for the rare case where the durable tier also needs wiping.
     pub async fn clear(&self) {
         self.product_cache.invalidate_all();
         self.entry_count.store(0, std::sync::atomic::Ordering::Relaxed);
+        self.memory_usage_bytes.store(0, std::sync::atomic::Ordering::Relaxed);
+        self.access_counts.clear();
+        self.expiry.clear();
+        self.lru_order.write().await.clear();
+        self.sync().await;
         info!("🧹 Product cache cleared");
     }
-    
+
+    /// Wipe the durable backing tier, if one is configured. Separate from
+    /// `clear`/`perform_daily_cache_reset` (which only ever touch the hot
+    /// tier) since discarding the durable tier is a deliberate, rarer
+    /// operation.
+    pub async fn clear_backing(&self) -> Result<()> {
+        if let Some(backing) = self.backing.read().await.clone() {
+            backing.clear()?;
+        }
+        Ok(())
+    }
+
+    /// Serialize every live product and admin entry, plus `CacheStats`, to a
+    /// single compact file at `path` (postcard, not JSON, since this is
+    /// written/read on every restart and never hand-inspected). Distinct
+    /// from the `backing` tier: that's a continuously-written-through `sled`
+    /// database rooted at `base_directory`, while this is a point-in-time
+    /// export a caller can copy, ship, or restore from elsewhere.
+    pub async fn snapshot_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut product_entries = Vec::new();
+        for (key, value) in self.product_cache.iter() {
+            let expires_at = self
+                .expiry
+                .get(key.as_str())
+                .map(|entry| entry.expires_at)
+                .unwrap_or_else(|| Utc::now() + chrono::Duration::seconds(self.ttl_seconds as i64));
+            product_entries.push(SnapshotEntry {
+                key: (*key).clone(),
+                value: value.clone(),
+                expires_at,
+            });
+        }
+
+        let admin_entries = self
+            .admin_cache
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+
+        let snapshot = CacheSnapshot {
+            product_entries,
+            admin_entries,
+            stats: self.get_cache_stats().await,
+        };
+
+        let bytes = postcard::to_allocvec(&snapshot)?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Repopulate the product and admin caches (and `CacheStats`) from a file
+    /// written by `snapshot_to`. A product entry whose `expires_at` has
+    /// already passed is restored with an already-elapsed TTL, so the next
+    /// `get` treats it as a miss rather than reviving stale data.
+    pub async fn restore_from(&self, path: impl AsRef<Path>) -> Result<()> {
+        let bytes = std::fs::read(path)?;
+        let snapshot: CacheSnapshot = postcard::from_bytes(&bytes)?;
+        let now = Utc::now();
+
+        for entry in snapshot.product_entries {
+            let remaining = (entry.expires_at - now).to_std().unwrap_or(Duration::ZERO);
+            self.insert_with_ttl(entry.key, entry.value, remaining).await;
+        }
+
+        for (key, value) in snapshot.admin_entries {
+            self.insert_admin(key, value);
+        }
+
+        self.hit_count.insert("product_cache".to_string(), snapshot.stats.hit_count);
+        self.miss_count.insert("product_cache".to_string(), snapshot.stats.miss_count);
+        self.eviction_count.insert("product_cache".to_string(), snapshot.stats.eviction_count);
+        self.capacity_eviction_count.insert("product_cache".to_string(), snapshot.stats.capacity_eviction_count);
+        self.expired_eviction_count.insert("product_cache".to_string(), snapshot.stats.expired_eviction_count);
+        self.explicit_eviction_count.insert("product_cache".to_string(), snapshot.stats.explicit_eviction_count);
+        self.replaced_eviction_count.insert("product_cache".to_string(), snapshot.stats.replaced_eviction_count);
+        self.expired_count.insert("product_cache".to_string(), snapshot.stats.expired_count);
+        self.last_reset_time.insert("product_cache".to_string(), snapshot.stats.last_reset_time);
+
+        Ok(())
+    }
+
     /// Get admin cache value
     pub fn get_admin(&self, key: &str) -> Option<String> {
         self.admin_cache.get(key).map(|entry| entry.value().clone())
@@ -167,10 +1044,24 @@ impl CacheManager {
         self.hit_count.insert("product_cache".to_string(), 0);
         self.miss_count.insert("product_cache".to_string(), 0);
         self.eviction_count.insert("product_cache".to_string(), 0);
-        
+        self.capacity_eviction_count.insert("product_cache".to_string(), 0);
+        self.expired_eviction_count.insert("product_cache".to_string(), 0);
+        self.explicit_eviction_count.insert("product_cache".to_string(), 0);
+        self.replaced_eviction_count.insert("product_cache".to_string(), 0);
+        self.expired_count.insert("product_cache".to_string(), 0);
+        self.backing_hit_count.insert("product_cache".to_string(), 0);
+        self.backing_miss_count.insert("product_cache".to_string(), 0);
+        self.pressure_eviction_count.insert("product_cache".to_string(), 0);
+
         // Update reset time
         self.last_reset_time.insert("product_cache".to_string(), Utc::now());
-        
+
+        // `clear` above already syncs `product_cache`, but the stats reset
+        // just above doesn't touch moka at all -- sync again so a caller
+        // reading `get_cache_stats` right after this returns never races
+        // moka's own deferred housekeeping.
+        self.sync().await;
+
         info!("✅ Daily cache reset completed");
         Ok(())
     }
@@ -193,22 +1084,69 @@ impl CacheManager {
             .get("product_cache")
             .map(|entry| *entry.value())
             .unwrap_or(0);
-        
+
+        let capacity_eviction_count = self.capacity_eviction_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
+        let expired_eviction_count = self.expired_eviction_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
+        let explicit_eviction_count = self.explicit_eviction_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
+        let replaced_eviction_count = self.replaced_eviction_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
+        let expired_count = self.expired_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
         let last_reset_time = self.last_reset_time
             .get("product_cache")
             .map(|entry| *entry.value())
             .unwrap_or_else(|| Utc::now());
-        
-        // Estimate memory usage (rough calculation)
-        let memory_usage_bytes = total_entries * 1024; // Rough estimate
-        
+
+        let backing_hit_count = self.backing_hit_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
+        let backing_miss_count = self.backing_miss_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
+        let pressure_eviction_count = self.pressure_eviction_count
+            .get("product_cache")
+            .map(|entry| *entry.value())
+            .unwrap_or(0);
+
+        let memory_usage_bytes = self.memory_usage_bytes.load(std::sync::atomic::Ordering::Relaxed);
+
         CacheStats {
             total_entries,
             hit_count,
             miss_count,
             eviction_count,
+            capacity_eviction_count,
+            expired_eviction_count,
+            explicit_eviction_count,
+            replaced_eviction_count,
+            expired_count,
             memory_usage_bytes,
             last_reset_time,
+            backing_hit_count,
+            backing_miss_count,
+            pressure_eviction_count,
         }
     }
     
@@ -0,0 +1,142 @@
+use crate::config::ServiceConfig;
+use crate::scheduler::parse_interval;
+use crate::DailyUpdateServiceV2;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand};
+
+/// Command-line entry point for `daily-update-service`. With no subcommand
+/// this parses to a daemon-mode run (handled by `main`); with a subcommand
+/// it dispatches a one-off operator command against the same
+/// `DailyUpdateServiceV2` the daemon uses.
+#[derive(Debug, Parser)]
+#[command(name = "daily-update-service", about = "Daily product update service")]
+pub struct Cli {
+    /// Override the update schedule with a human-duration string like
+    /// "12h", "30m", or "1h30m" (see `scheduler::parse_interval`).
+    #[arg(long)]
+    pub interval: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run a single hourly-update pass immediately, then exit.
+    RunOnce,
+    /// Print current service statistics and exit.
+    Status,
+    /// Approve a pending product by slug.
+    Approve {
+        slug: String,
+        #[arg(long)]
+        username: String,
+        #[arg(long)]
+        password: String,
+    },
+    /// List products awaiting review.
+    Pending,
+}
+
+impl Cli {
+    /// Apply `--interval`, if given, to `config.update_config`.
+    pub fn apply_interval_override(&self, config: &mut ServiceConfig) -> Result<()> {
+        if let Some(interval) = &self.interval {
+            let duration = parse_interval(interval)?;
+            config.update_config.update_interval_hours = (duration.as_secs() / 3600).max(1);
+            config.update_config.check_interval_minutes = (duration.as_secs() / 60).max(1);
+        }
+        Ok(())
+    }
+}
+
+/// Render a relative "N units ago" string the way operators expect from
+/// `status`/`pending` output, e.g. "3 hours ago", "just now".
+pub fn format_relative(time: DateTime<Utc>) -> String {
+    let seconds = (Utc::now() - time).num_seconds().max(0);
+
+    if seconds < 60 {
+        return "just now".to_string();
+    }
+
+    let (amount, unit) = if seconds < 3600 {
+        (seconds / 60, "minute")
+    } else if seconds < 86_400 {
+        (seconds / 3600, "hour")
+    } else if seconds < 30 * 86_400 {
+        (seconds / 86_400, "day")
+    } else if seconds < 365 * 86_400 {
+        (seconds / (30 * 86_400), "month")
+    } else {
+        (seconds / (365 * 86_400), "year")
+    };
+
+    format!("{} {}{} ago", amount, unit, if amount == 1 { "" } else { "s" })
+}
+
+/// Run the subcommand in `cli.command` (if any) against a freshly
+/// initialized service built from `config`. Returns `true` if a subcommand
+/// was dispatched, in which case the caller should exit rather than falling
+/// through to daemon mode.
+pub async fn dispatch(cli: &Cli, config: ServiceConfig) -> Result<bool> {
+    let Some(command) = &cli.command else {
+        return Ok(false);
+    };
+
+    let service = DailyUpdateServiceV2::new(config);
+    service.initialize().await?;
+
+    match command {
+        Command::RunOnce => {
+            service.force_hourly_update().await?;
+            println!("Ran one update pass");
+        }
+        Command::Status => {
+            let stats = service.get_service_stats().await;
+            println!("running: {}", stats.is_running);
+            println!("last update: {}", format_relative(stats.last_update_time));
+            println!(
+                "processed: {} (accepted: {}, denied: {})",
+                stats.total_processed, stats.total_accepted, stats.total_denied
+            );
+            println!(
+                "pending queue: {} (highest processed update_id: {})",
+                stats.pending_queue_depth,
+                stats
+                    .highest_processed_update_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| "none".to_string())
+            );
+            println!("average worker occupancy: {:.1}%", stats.average_worker_occupancy * 100.0);
+        }
+        Command::Approve { slug, username, password } => {
+            service.approve_product(slug, username, password).await?;
+            println!("Approved '{}'", slug);
+        }
+        Command::Pending => {
+            let store = service.product_store.read().await;
+            let store = store
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("no product store configured"))?;
+            let pending = store.list_pending().await?;
+
+            for product in &pending {
+                let reviewed = product
+                    .reviewed_at
+                    .map(format_relative)
+                    .unwrap_or_else(|| "never".to_string());
+                println!(
+                    "{}\t{}\tupdated {}\treviewed {}",
+                    product.slug,
+                    product.name,
+                    format_relative(product.updated_at),
+                    reviewed
+                );
+            }
+            println!("{} pending", pending.len());
+        }
+    }
+
+    Ok(true)
+}
@@ -15,6 +15,16 @@ pub struct ServiceConfig {
     
     /// Update schedule configuration
     pub update_config: UpdateConfig,
+
+    /// Postgres connection URL for the `ProductStore`. When unset, products
+    /// are only held in memory (the pre-persistence behavior).
+    pub database_url: Option<String>,
+
+    /// Retailer catalog scraping configuration.
+    pub scraper_config: ScraperConfig,
+
+    /// Reviewer authentication configuration.
+    pub auth_config: AuthConfig,
 }
 
 /// Cache configuration
@@ -42,6 +52,39 @@ pub struct GoConfig {
     
     /// Command timeout in seconds
     pub command_timeout: u64,
+
+    /// Maximum number of concurrent Go transport checkouts (see
+    /// `go_worker_pool::Pool`). Bounds how many `migrate_product` calls may
+    /// be in flight at once, independent of `UpdateConfig::batch_concurrency`
+    /// which only bounds concurrency within a single `migrate_products_batch`
+    /// call.
+    pub pool_max_size: usize,
+}
+
+/// Retailer catalog scraping configuration: scheduling plus politeness
+/// controls shared by every `RetailerScraper`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScraperConfig {
+    /// Whether the scheduled scraping pass runs at all.
+    pub enabled: bool,
+
+    /// How often to run a full scrape pass, e.g. "6h" (parsed the same way
+    /// as `UpdateConfig` intervals, via `scheduler::parse_interval`).
+    pub interval: String,
+
+    /// Maximum concurrent in-flight requests to a single host.
+    pub per_host_concurrency: usize,
+
+    /// Minimum delay between requests to the same host, in milliseconds.
+    pub min_request_delay_ms: u64,
+}
+
+/// Reviewer authentication configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthConfig {
+    /// bcrypt cost factor used when hashing reviewer passwords. Higher is
+    /// slower but more resistant to offline brute force.
+    pub bcrypt_cost: u32,
 }
 
 /// Update schedule configuration
@@ -55,6 +98,22 @@ pub struct UpdateConfig {
     
     /// Enable automatic updates
     pub enable_automatic_updates: bool,
+
+    /// Throttle applied between batches in `process_accepted_products`: the
+    /// worker sleeps `tranquility * (time spent on the last batch)` after
+    /// each one, so a large backlog can be drained slowly instead of
+    /// saturating the Go integration/database. `0` (the default) is full
+    /// speed -- no throttle.
+    pub tranquility: u32,
+
+    /// Maximum number of accepted products migrated per `migrate_products_batch`
+    /// call in `process_accepted_products`, instead of one IPC/process call
+    /// per product.
+    pub max_batch_size: usize,
+
+    /// Maximum in-flight `migrate_product` calls within one batch (see
+    /// `GoIntegration::migrate_products_batch`).
+    pub batch_concurrency: usize,
 }
 
 impl Default for ServiceConfig {
@@ -64,6 +123,28 @@ impl Default for ServiceConfig {
             cache_config: CacheConfig::default(),
             go_config: GoConfig::default(),
             update_config: UpdateConfig::default(),
+            database_url: None,
+            scraper_config: ScraperConfig::default(),
+            auth_config: AuthConfig::default(),
+        }
+    }
+}
+
+impl Default for ScraperConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: "6h".to_string(),
+            per_host_concurrency: 2,
+            min_request_delay_ms: 1000,
+        }
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            bcrypt_cost: bcrypt::DEFAULT_COST,
         }
     }
 }
@@ -85,6 +166,7 @@ impl Default for GoConfig {
             binary_path: PathBuf::from("go-supabase/main"),
             working_directory: PathBuf::from("go-supabase"),
             command_timeout: 30,
+            pool_max_size: 4,
         }
     }
 }
@@ -95,6 +177,9 @@ impl Default for UpdateConfig {
             update_interval_hours: 1,
             check_interval_minutes: 5,
             enable_automatic_updates: true,
+            tranquility: 0,
+            max_batch_size: 25,
+            batch_concurrency: 4,
         }
     }
 }
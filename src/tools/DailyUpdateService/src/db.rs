@@ -0,0 +1,241 @@
+use crate::auth::Reviewer;
+use crate::migrations::MigrationRunner;
+use crate::ProductData;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Directory of timestamp-prefixed `.sql` migration files applied before a
+/// store is handed back to callers.
+const MIGRATIONS_DIR: &str = "migrations";
+
+/// The only meaningful review states: -1 (denied), 0 (pending), 1 (approved).
+/// Anything else must never reach the database.
+const VALID_APPROVAL_STATUSES: [i32; 3] = [-1, 0, 1];
+
+fn validate_approval_status(status: i32) -> Result<()> {
+    if VALID_APPROVAL_STATUSES.contains(&status) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "invalid approval_status {} (expected one of {:?})",
+            status,
+            VALID_APPROVAL_STATUSES
+        ))
+    }
+}
+
+/// Persistence for `ProductData`, so `DailyUpdateServiceV2` can read/write
+/// products instead of holding them only in memory.
+#[async_trait]
+pub trait ProductStore: Send + Sync {
+    async fn insert(&self, product: &ProductData) -> Result<()>;
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<ProductData>>;
+    async fn list_pending(&self) -> Result<Vec<ProductData>>;
+
+    /// Whether a product with this id has already been persisted, so callers
+    /// can detect a collision before retrying with a freshly generated id.
+    async fn product_id_exists(&self, id: &Uuid) -> Result<bool>;
+
+    /// Approve a pending product, stamping `reviewed_by`/`reviewed_at` with
+    /// `reviewer`'s identity. `reviewer` must already be authenticated via
+    /// `ReviewerRegistry::authenticate` -- this call does not check a
+    /// password.
+    async fn approve(&self, slug: &str, reviewer: &Reviewer) -> Result<()>;
+
+    /// Reject a pending product, requiring a non-empty `rejection_reason`
+    /// and stamping `reviewed_by`/`reviewed_at` with `reviewer`'s identity.
+    async fn reject(&self, slug: &str, reviewer: &Reviewer, rejection_reason: &str) -> Result<()>;
+}
+
+/// `ProductStore` backed by PostgreSQL via `sqlx`.
+pub struct PostgresProductStore {
+    pool: PgPool,
+}
+
+impl PostgresProductStore {
+    /// Connect to `database_url`, apply any pending migrations from
+    /// `migrations/` (skipping already-applied versions, failing loudly on
+    /// a checksum mismatch), and return a ready-to-use store.
+    pub async fn connect(database_url: &str) -> Result<Self> {
+        MigrationRunner::new(PathBuf::from(MIGRATIONS_DIR), database_url.to_string())
+            .run()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let pool = PgPoolOptions::new()
+            .max_connections(10)
+            .connect(database_url)
+            .await?;
+
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl ProductStore for PostgresProductStore {
+    async fn insert(&self, product: &ProductData) -> Result<()> {
+        validate_approval_status(product.approval_status)?;
+
+        sqlx::query(
+            "INSERT INTO products (
+                id, brand_id, category, name, slug, image_url, description,
+                servings_per_container, serving_size_g, transparency_score,
+                confidence_level, dosage_rating, danger_rating,
+                community_rating, total_reviews, approval_status,
+                submitted_by, reviewed_by, rejection_reason,
+                created_at, updated_at, reviewed_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)",
+        )
+        .bind(product.id)
+        .bind(product.brand_id)
+        .bind(&product.category)
+        .bind(&product.name)
+        .bind(&product.slug)
+        .bind(&product.image_url)
+        .bind(&product.description)
+        .bind(product.servings_per_container)
+        .bind(product.serving_size_g)
+        .bind(product.transparency_score)
+        .bind(&product.confidence_level)
+        .bind(product.dosage_rating)
+        .bind(product.danger_rating)
+        .bind(product.community_rating)
+        .bind(product.total_reviews)
+        .bind(product.approval_status)
+        .bind(&product.submitted_by)
+        .bind(&product.reviewed_by)
+        .bind(&product.rejection_reason)
+        .bind(product.created_at)
+        .bind(product.updated_at)
+        .bind(product.reviewed_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<ProductData>> {
+        let row = sqlx::query_as::<_, ProductRow>("SELECT * FROM products WHERE slug = $1")
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn list_pending(&self) -> Result<Vec<ProductData>> {
+        let rows = sqlx::query_as::<_, ProductRow>("SELECT * FROM products WHERE approval_status = 0")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn product_id_exists(&self, id: &Uuid) -> Result<bool> {
+        let (exists,): (bool,) =
+            sqlx::query_as("SELECT EXISTS(SELECT 1 FROM products WHERE id = $1)")
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(exists)
+    }
+
+    async fn approve(&self, slug: &str, reviewer: &Reviewer) -> Result<()> {
+        let now = Utc::now();
+        sqlx::query(
+            "UPDATE products
+             SET approval_status = 1, reviewed_by = $1, reviewed_at = $2, updated_at = $2
+             WHERE slug = $3",
+        )
+        .bind(reviewer.id.to_string())
+        .bind(now)
+        .bind(slug)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reject(&self, slug: &str, reviewer: &Reviewer, rejection_reason: &str) -> Result<()> {
+        if rejection_reason.trim().is_empty() {
+            return Err(anyhow::anyhow!("rejection_reason must not be empty"));
+        }
+
+        let now = Utc::now();
+        sqlx::query(
+            "UPDATE products
+             SET approval_status = -1, reviewed_by = $1, rejection_reason = $2, reviewed_at = $3, updated_at = $3
+             WHERE slug = $4",
+        )
+        .bind(reviewer.id.to_string())
+        .bind(rejection_reason)
+        .bind(now)
+        .bind(slug)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// Row shape for the `products` table, mapped into `ProductData`.
+#[derive(sqlx::FromRow)]
+struct ProductRow {
+    id: Uuid,
+    brand_id: Option<Uuid>,
+    category: String,
+    name: String,
+    slug: String,
+    image_url: Option<String>,
+    description: Option<String>,
+    servings_per_container: Option<i32>,
+    serving_size_g: Option<f64>,
+    transparency_score: Option<i32>,
+    confidence_level: Option<String>,
+    dosage_rating: Option<i32>,
+    danger_rating: Option<i32>,
+    community_rating: Option<f64>,
+    total_reviews: Option<i32>,
+    approval_status: i32,
+    submitted_by: String,
+    reviewed_by: Option<String>,
+    rejection_reason: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl From<ProductRow> for ProductData {
+    fn from(row: ProductRow) -> Self {
+        ProductData {
+            id: row.id,
+            brand_id: row.brand_id,
+            category: row.category,
+            name: row.name,
+            slug: row.slug,
+            image_url: row.image_url,
+            description: row.description,
+            servings_per_container: row.servings_per_container,
+            serving_size_g: row.serving_size_g,
+            transparency_score: row.transparency_score,
+            confidence_level: row.confidence_level,
+            dosage_rating: row.dosage_rating,
+            danger_rating: row.danger_rating,
+            community_rating: row.community_rating,
+            total_reviews: row.total_reviews,
+            approval_status: row.approval_status,
+            submitted_by: row.submitted_by,
+            reviewed_by: row.reviewed_by,
+            rejection_reason: row.rejection_reason,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            reviewed_at: row.reviewed_at,
+        }
+    }
+}
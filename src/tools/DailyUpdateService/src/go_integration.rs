@@ -1,13 +1,174 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use tracing::{info, error, warn};
 
+use crate::go_worker_pool::{Pool, PoolStatus};
+use crate::live_events::MigrationEventBridge;
+use crate::migrations::MigrationRunner;
+use crate::queue::MigrationQueue;
+use crate::scheduler::Scheduler;
+use crate::transport::{GoTransport, SubprocessTransport};
 use crate::ProductData;
 
+/// Callback invoked with (slug, success, error) once a queued migration's
+/// real outcome arrives off `products:migrate:results`. See
+/// `register_migration_result_listener`.
+type MigrationResultListener = Arc<dyn Fn(String, bool, Option<String>) + Send + Sync>;
+
+/// Default number of buffered products that triggers an immediate batch
+/// flush, absent an explicit `with_batching` call.
+const DEFAULT_BATCH_SIZE: usize = 100;
+
+/// Default maximum time buffered products may wait before a flush, absent
+/// an explicit `with_batching` call.
+const DEFAULT_BATCH_LINGER: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Default time `migrate_product` waits for a pool checkout, absent an
+/// explicit `with_worker_pool` call.
+const DEFAULT_POOL_CHECKOUT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Structured response returned by the Go component, as opposed to the raw
+/// stdout/exit-code signals `execute_go_command` deals in today.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoResponse {
+    pub success: bool,
+    pub message: Option<String>,
+    pub error: Option<String>,
+    pub data: Option<serde_json::Value>,
+}
+
+impl GoResponse {
+    /// Decode a raw Go component response into a typed `GoResponse`.
+    ///
+    /// Only the last complete top-level JSON object in `response` is
+    /// parsed, so stray non-JSON noise on stdout (e.g. log lines the Go
+    /// binary printed before its final result) doesn't break decoding.
+    /// Empty input, non-object input, and truncated/invalid JSON (e.g.
+    /// `{"success":}`) all map to `GoError::Parse` rather than silently
+    /// returning `true`/`false` like the old substring match did.
+    pub fn decode(response: &str) -> Result<Self, GoError> {
+        let json_slice = last_json_object(response)
+            .ok_or_else(|| GoError::Parse("no JSON object found in response".to_string()))?;
+
+        let value: serde_json::Value = serde_json::from_str(json_slice)
+            .map_err(|e| GoError::Parse(e.to_string()))?;
+
+        serde_json::from_value(value).map_err(|e| GoError::Parse(e.to_string()))
+    }
+
+    /// gjson-style dotted-path query over this response (including `data`),
+    /// e.g. `"data.products.#.name"` to collect the `name` field of every
+    /// element in the `products` array. The `#` token iterates the array at
+    /// that point in the path, applying the rest of the path to each
+    /// element; a bare trailing `#` returns the array's length instead. A
+    /// path segment that doesn't exist at any point returns `Value::Null`
+    /// rather than an error, so callers don't need a struct per command
+    /// just to pull one field out.
+    pub fn get(&self, path: &str) -> serde_json::Value {
+        let root = serde_json::to_value(self).unwrap_or(serde_json::Value::Null);
+        let segments: Vec<&str> = path.split('.').collect();
+        get_path(&root, &segments)
+    }
+}
+
+/// Return the last complete top-level `{...}` object in `text`, skipping
+/// over braces inside quoted strings.
+fn last_json_object(text: &str) -> Option<&str> {
+    let mut depth = 0i32;
+    let mut start: Option<usize> = None;
+    let mut last_object: Option<(usize, usize)> = None;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' => {
+                if depth == 0 {
+                    start = Some(i);
+                }
+                depth += 1;
+            }
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    if let Some(s) = start {
+                        last_object = Some((s, i + c.len_utf8()));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    last_object.map(|(s, e)| &text[s..e])
+}
+
+fn get_path(value: &serde_json::Value, segments: &[&str]) -> serde_json::Value {
+    let Some((head, rest)) = segments.split_first() else {
+        return value.clone();
+    };
+
+    if *head == "#" {
+        return match value.as_array() {
+            Some(items) if rest.is_empty() => serde_json::Value::Number(items.len().into()),
+            Some(items) => serde_json::Value::Array(
+                items.iter().map(|item| get_path(item, rest)).collect(),
+            ),
+            None => serde_json::Value::Null,
+        };
+    }
+
+    let next = match value.as_array() {
+        Some(items) => head.parse::<usize>().ok().and_then(|i| items.get(i)),
+        None => value.get(*head),
+    };
+
+    match next {
+        Some(next) => get_path(next, rest),
+        None => serde_json::Value::Null,
+    }
+}
+
+/// Errors produced while decoding or acting on a Go component response.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GoError {
+    #[error("failed to parse Go response: {0}")]
+    Parse(String),
+    #[error("Go component reported failure: {0}")]
+    Failed(String),
+}
+
+/// Per-item outcome of `migrate_products_batch`, keyed by product slug so
+/// one bad product doesn't abort the rest of the run.
+#[derive(Debug, Clone, Default)]
+pub struct BatchReport {
+    pub succeeded: Vec<String>,
+    pub failed: Vec<(String, GoError)>,
+    /// Durably `XADD`ed onto `products:migrate` (see `with_queue`) but not
+    /// yet confirmed -- the real outcome hasn't arrived off
+    /// `products:migrate:results` yet. Callers must not treat these as
+    /// `succeeded`; register a `register_migration_result_listener` callback
+    /// to learn the eventual outcome for each slug.
+    pub queued: Vec<String>,
+}
+
 /// Go integration statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoStats {
@@ -18,6 +179,28 @@ pub struct GoStats {
     pub go_binary_path: String,
     pub working_directory: String,
     pub is_initialized: bool,
+    /// Name of the active `GoTransport` ("subprocess" or "http").
+    pub transport: String,
+    /// Timestamp of the last scheduled run, if a `Scheduler` is attached.
+    pub last_scheduled_run: Option<DateTime<Utc>>,
+    /// Consecutive scheduled-run failures, if a `Scheduler` is attached.
+    pub consecutive_failures: u64,
+    /// Results-stream entries awaiting acknowledgment, if a `MigrationQueue`
+    /// is attached (0 otherwise).
+    pub queue_pending: u64,
+    /// Entries this process has reclaimed from a stale consumer via
+    /// `XAUTOCLAIM`, if a `MigrationQueue` is attached (0 otherwise).
+    pub queue_reclaimed: u64,
+    /// Whether the live migration-event WebSocket is currently connected, if
+    /// one was configured via `with_live_events` (`None` otherwise).
+    pub live_events_connected: Option<bool>,
+    /// Occupancy of the bounded Go worker pool (see `go_worker_pool::Pool`),
+    /// if one was configured via `with_worker_pool` (`None` otherwise).
+    pub pool_status: Option<PoolStatus>,
+    /// Products currently sitting in the batching buffer, awaiting a flush.
+    pub buffered_count: usize,
+    /// When the batching buffer was last flushed to the transport, if ever.
+    pub last_flush_time: Option<DateTime<Utc>>,
 }
 
 /// Go Integration - Handles communication with Go Supabase component (temporary products system)
@@ -31,22 +214,102 @@ pub struct GoStats {
 pub struct GoIntegration {
     go_supabase_binary: String,
     go_working_directory: String,
-    
+
+    // Active transport: spawns the Go binary per call by default, or talks
+    // HTTP to a long-running Go service when constructed via
+    // `new_with_transport`.
+    transport: Arc<dyn GoTransport>,
+
+    // Schema migrations for the Go component's database, applied on
+    // `initialize` before the transport is considered ready.
+    migrations_database_url: Option<String>,
+    migrations_dir: PathBuf,
+
+    // Scheduler driving recurring daily-update runs (see `scheduler` module);
+    // `None` until `with_scheduler` is used.
+    scheduler: Option<Arc<Scheduler>>,
+
+    // Redis Streams migration queue (see `queue` module); `None` until
+    // `with_queue` is used, in which case `migrate_product` enqueues onto
+    // `products:migrate` instead of calling `transport` directly.
+    queue: Option<Arc<MigrationQueue>>,
+
+    // Callbacks notified of each queued migration's real outcome once
+    // `initialize`'s background poll task reads it off
+    // `products:migrate:results`; see `register_migration_result_listener`.
+    // Empty (and never notified) when no `MigrationQueue` is attached.
+    migration_result_listeners: Arc<tokio::sync::RwLock<Vec<MigrationResultListener>>>,
+
+    // Live migration-event WebSocket bridge (see `live_events` module);
+    // `None` until `with_live_events` is used, in which case `initialize`
+    // spawns a background task that keeps `successful_inserts`/
+    // `failed_inserts` current from push events instead of only updating
+    // them on an explicit `migrate_product`/`migrate_products_batch` call.
+    live_events: Option<Arc<MigrationEventBridge>>,
+
+    // In-memory batching producer: `enqueue` pushes here, and the
+    // background flusher spawned by `initialize` drains it into one
+    // `migrate-products-batch` call whenever it reaches `batch_size` or
+    // `batch_linger` has elapsed, whichever comes first.
+    batch_buffer: Arc<tokio::sync::Mutex<Vec<ProductData>>>,
+    batch_size: usize,
+    batch_linger: std::time::Duration,
+    batch_flush_notify: Arc<tokio::sync::Notify>,
+    // Set by `flush_batch` each time a flush actually reaches the transport
+    // (successfully or not -- only a re-queued transient failure leaves this
+    // unset). Surfaced via `get_go_stats` so an operator can tell whether the
+    // background flusher is still making progress.
+    last_flush_time: Arc<tokio::sync::RwLock<Option<DateTime<Utc>>>>,
+
+    // Bounded pool of concurrent transport checkouts (see `go_worker_pool`).
+    // `pool_max_size` is set by `with_worker_pool`, but the `Pool` itself is
+    // only built by `initialize` -- it has to wrap the final `transport`,
+    // which isn't resolved until `initialize` rebuilds it around the
+    // resolved binary path. `worker_pool` stays `None` (and `migrate_product`
+    // calls `transport` directly) if `with_worker_pool` was never used.
+    pool_max_size: Option<usize>,
+    pool_checkout_timeout: std::time::Duration,
+    worker_pool: Option<Arc<Pool>>,
+
     // Statistics
     successful_inserts: Arc<AtomicU64>,
     failed_inserts: Arc<AtomicU64>,
     batch_operations: Arc<AtomicU64>,
     pub go_calls: Arc<AtomicU64>,
-    
+
     pub is_initialized: Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl GoIntegration {
-    /// Create a new GoIntegration instance
+    /// Create a new GoIntegration instance using the default subprocess transport
     pub fn new() -> Self {
+        Self::new_with_transport(Arc::new(SubprocessTransport {
+            binary_path: String::new(),
+            working_directory: String::new(),
+        }))
+    }
+
+    /// Create a new GoIntegration instance backed by a specific `GoTransport`
+    /// (e.g. `HttpTransport` to point at a persistent Go microservice).
+    pub fn new_with_transport(transport: Arc<dyn GoTransport>) -> Self {
         Self {
             go_supabase_binary: String::new(),
             go_working_directory: String::new(),
+            transport,
+            migrations_database_url: None,
+            migrations_dir: PathBuf::from("go-supabase/migrations"),
+            scheduler: None,
+            queue: None,
+            migration_result_listeners: Arc::new(tokio::sync::RwLock::new(Vec::new())),
+            live_events: None,
+            batch_buffer: Arc::new(tokio::sync::Mutex::new(Vec::new())),
+            batch_size: DEFAULT_BATCH_SIZE,
+            batch_linger: DEFAULT_BATCH_LINGER,
+            batch_flush_notify: Arc::new(tokio::sync::Notify::new()),
+            last_flush_time: Arc::new(tokio::sync::RwLock::new(None)),
+            pool_max_size: None,
+            pool_checkout_timeout: DEFAULT_POOL_CHECKOUT_TIMEOUT,
+            worker_pool: None,
             successful_inserts: Arc::new(AtomicU64::new(0)),
             failed_inserts: Arc::new(AtomicU64::new(0)),
             batch_operations: Arc::new(AtomicU64::new(0)),
@@ -54,53 +317,338 @@ impl GoIntegration {
             is_initialized: Arc::new(std::sync::atomic::AtomicBool::new(false)),
         }
     }
-    
+
+    /// Configure the schema migration runner for the Go component's
+    /// database. When set, pending migrations are applied by `initialize`
+    /// before the transport is marked ready.
+    pub fn with_migrations(mut self, migrations_dir: impl Into<PathBuf>, database_url: impl Into<String>) -> Self {
+        self.migrations_dir = migrations_dir.into();
+        self.migrations_database_url = Some(database_url.into());
+        self
+    }
+
+    /// Attach a `Scheduler` whose last-run timestamp and consecutive-failure
+    /// count are surfaced through `get_go_stats`.
+    pub fn with_scheduler(mut self, scheduler: Arc<Scheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// Attach a `MigrationQueue`. Once set, `migrate_product` durably
+    /// `XADD`s onto `products:migrate` instead of calling `transport`
+    /// directly, and `initialize` spawns a background task that drains
+    /// `products:migrate:results` into `successful_inserts`/`failed_inserts`.
+    pub fn with_queue(mut self, queue: Arc<MigrationQueue>) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
+    /// Register a callback invoked with (slug, success, error) once a
+    /// queued migration's real outcome arrives off
+    /// `products:migrate:results` -- the only way to learn the outcome of a
+    /// `migrate_product`/`migrate_products_batch` call that returned
+    /// `BatchReport::queued` (durably enqueued, not yet confirmed) rather
+    /// than `succeeded`/`failed`. Multiple listeners may be registered; each
+    /// is called for every outcome, in registration order. Never invoked if
+    /// no `MigrationQueue` is attached via `with_queue`.
+    pub async fn register_migration_result_listener<F>(&self, listener: F)
+    where
+        F: Fn(String, bool, Option<String>) + Send + Sync + 'static,
+    {
+        self.migration_result_listeners.write().await.push(Arc::new(listener));
+    }
+
+    /// Subscribe to the Go component's live migration-event WebSocket at
+    /// `ws_url` (e.g. `HttpTransport`'s base URL with a `ws://`/`wss://`
+    /// scheme and an `Authorization: Bearer <token>` header when `token` is
+    /// set). Once attached, `initialize` spawns a background task that keeps
+    /// `successful_inserts`/`failed_inserts` current from push events, and
+    /// `get_go_stats` reports connection health via `live_events_connected`.
+    pub fn with_live_events(mut self, ws_url: impl Into<String>, token: Option<String>) -> Self {
+        self.live_events = Some(Arc::new(MigrationEventBridge::new(
+            ws_url,
+            token,
+            self.successful_inserts.clone(),
+            self.failed_inserts.clone(),
+        )));
+        self
+    }
+
+    /// Override the batching producer's flush thresholds (defaults:
+    /// `DEFAULT_BATCH_SIZE` products / `DEFAULT_BATCH_LINGER`). See `enqueue`.
+    pub fn with_batching(mut self, batch_size: usize, max_linger: std::time::Duration) -> Self {
+        self.batch_size = batch_size.max(1);
+        self.batch_linger = max_linger;
+        self
+    }
+
+    /// Bound `migrate_product` to at most `max_size` concurrent transport
+    /// checkouts (see `go_worker_pool::Pool`), each waiting up to
+    /// `checkout_timeout` for a free slot. Without this, `migrate_product`
+    /// calls `transport` directly with no concurrency bound of its own. The
+    /// `Pool` itself is built by `initialize`, once the transport it wraps
+    /// is finalized.
+    pub fn with_worker_pool(mut self, max_size: usize, checkout_timeout: std::time::Duration) -> Self {
+        self.pool_max_size = Some(max_size);
+        self.pool_checkout_timeout = checkout_timeout;
+        self
+    }
+
     /// Initialize Go integration with binary path
     pub async fn initialize(&mut self) -> Result<bool> {
         info!("🔧 Initializing GoIntegration...");
-        
-        // Set default paths
-        self.go_supabase_binary = "go-supabase/main".to_string();
-        self.go_working_directory = "go-supabase".to_string();
-        
-        // Check if Go binary exists and is executable
-        if !self.check_go_binary().await? {
-            error!("❌ Go binary not found or not executable");
-            return Ok(false);
+
+        // Apply any pending schema migrations before anything else touches
+        // the Go component's database.
+        if let Some(database_url) = &self.migrations_database_url {
+            let runner = MigrationRunner::new(self.migrations_dir.clone(), database_url.clone());
+            match runner.run().await {
+                Ok(applied) => info!("✅ Applied {} pending migration(s)", applied),
+                Err(e) => {
+                    error!("❌ Migration run failed: {}", e);
+                    return Err(anyhow::anyhow!(e));
+                }
+            }
+        }
+
+        // Only resolve default binary paths (and rebuild the subprocess
+        // transport around them) if the caller didn't already pick a
+        // transport such as HttpTransport via `new_with_transport`.
+        if self.transport.name() == "subprocess" {
+            self.go_supabase_binary = "go-supabase/main".to_string();
+            self.go_working_directory = "go-supabase".to_string();
+            self.transport = Arc::new(SubprocessTransport {
+                binary_path: self.go_supabase_binary.clone(),
+                working_directory: self.go_working_directory.clone(),
+            });
+
+            // Check if Go binary exists and is executable
+            if !self.check_go_binary().await? {
+                error!("❌ Go binary not found or not executable");
+                return Ok(false);
+            }
         }
-        
+
+        // Build the bounded worker pool around the now-final transport, if
+        // `with_worker_pool` was used. Deferred to here (rather than built
+        // eagerly in `with_worker_pool`) since the subprocess branch above
+        // may have just replaced `self.transport`.
+        if let Some(max_size) = self.pool_max_size {
+            self.worker_pool = Some(Arc::new(Pool::new(self.transport.clone(), max_size)));
+        }
+
         // Verify Go component is working
         if !self.verify_go_component().await? {
             error!("❌ Go component verification failed");
             return Ok(false);
         }
-        
+
         self.is_initialized.store(true, Ordering::Relaxed);
-        info!("✅ GoIntegration initialized successfully");
+        info!("✅ GoIntegration initialized successfully ({} transport)", self.transport.name());
+
+        // Drain `products:migrate:results` for as long as this instance
+        // stays initialized; `shutdown` flips `is_initialized` back to
+        // false, which stops the loop on its next iteration.
+        if let Some(queue) = self.queue.clone() {
+            let is_initialized = self.is_initialized.clone();
+            let migration_result_listeners = self.migration_result_listeners.clone();
+            tokio::spawn(async move {
+                while is_initialized.load(Ordering::Relaxed) {
+                    match queue.poll_results(32, std::time::Duration::from_secs(5)).await {
+                        Ok(outcomes) => {
+                            for outcome in outcomes {
+                                for listener in migration_result_listeners.read().await.iter() {
+                                    listener(outcome.slug.clone(), outcome.success, outcome.error.clone());
+                                }
+                            }
+                        }
+                        Err(e) => error!("❌ Error polling migration results: {}", e),
+                    }
+                    if let Err(e) = queue.reclaim_stale().await {
+                        error!("❌ Error reclaiming stale migration entries: {}", e);
+                    }
+                }
+            });
+        }
+
+        // Keep the live migration-event WebSocket connected for as long as
+        // this instance stays initialized; `MigrationEventBridge::run`
+        // reconnects on its own on disconnect.
+        if let Some(bridge) = self.live_events.clone() {
+            let is_initialized = self.is_initialized.clone();
+            tokio::spawn(async move {
+                bridge.run(is_initialized).await;
+            });
+        }
+
+        // Background batching producer: drains `batch_buffer` into one
+        // `migrate-products-batch` transport call per flush, triggered
+        // either by `batch_flush_notify` (fired by `enqueue` once
+        // `batch_size` is reached) or by `batch_linger` elapsing, whichever
+        // comes first. Runs for as long as this instance stays initialized.
+        {
+            let is_initialized = self.is_initialized.clone();
+            let transport = self.transport.clone();
+            let buffer = self.batch_buffer.clone();
+            let notify = self.batch_flush_notify.clone();
+            let linger = self.batch_linger;
+            let successful_inserts = self.successful_inserts.clone();
+            let failed_inserts = self.failed_inserts.clone();
+            let batch_operations = self.batch_operations.clone();
+            let last_flush_time = self.last_flush_time.clone();
+
+            tokio::spawn(async move {
+                while is_initialized.load(Ordering::Relaxed) {
+                    tokio::select! {
+                        _ = notify.notified() => {}
+                        _ = tokio::time::sleep(linger) => {}
+                    }
+
+                    let batch = {
+                        let mut buffer = buffer.lock().await;
+                        if buffer.is_empty() {
+                            continue;
+                        }
+                        std::mem::take(&mut *buffer)
+                    };
+
+                    flush_batch(
+                        &transport,
+                        batch,
+                        &buffer,
+                        &successful_inserts,
+                        &failed_inserts,
+                        &batch_operations,
+                        &last_flush_time,
+                    )
+                    .await;
+                }
+            });
+        }
+
         Ok(true)
     }
-    
-    /// Migrate accepted product from temporary table to main table via Go component
+
+    /// Buffer `product` for the batching producer instead of migrating it
+    /// immediately. The background flusher spawned by `initialize` sends it
+    /// (along with the rest of the buffer) in one `migrate-products-batch`
+    /// call once the buffer reaches `batch_size` products or `batch_linger`
+    /// elapses, whichever comes first.
+    pub async fn enqueue(&self, product: ProductData) -> Result<()> {
+        if !self.is_initialized.load(Ordering::Relaxed) {
+            return Err(anyhow::anyhow!("GoIntegration not initialized"));
+        }
+
+        let should_flush_now = {
+            let mut buffer = self.batch_buffer.lock().await;
+            buffer.push(product);
+            buffer.len() >= self.batch_size
+        };
+
+        if should_flush_now {
+            self.batch_flush_notify.notify_one();
+        }
+
+        Ok(())
+    }
+
+    /// Immediately flush whatever is currently buffered via `enqueue`,
+    /// without waiting for `batch_size`/`batch_linger`. A no-op if the
+    /// buffer is empty.
+    pub async fn flush(&self) -> Result<()> {
+        let batch = {
+            let mut buffer = self.batch_buffer.lock().await;
+            if buffer.is_empty() {
+                return Ok(());
+            }
+            std::mem::take(&mut *buffer)
+        };
+
+        flush_batch(
+            &self.transport,
+            batch,
+            &self.batch_buffer,
+            &self.successful_inserts,
+            &self.failed_inserts,
+            &self.batch_operations,
+            &self.last_flush_time,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// Products currently sitting in the batching buffer, awaiting a flush.
+    pub async fn buffered_count(&self) -> usize {
+        self.batch_buffer.lock().await.len()
+    }
+
+    /// When the batching buffer was last flushed to the transport, if ever.
+    /// Left unchanged by a re-queued transient failure (see `flush_batch`),
+    /// since that flush never actually reached the transport.
+    pub async fn last_flush_time(&self) -> Option<DateTime<Utc>> {
+        *self.last_flush_time.read().await
+    }
+
+    /// Migrate accepted product from temporary table to main table via Go component.
+    ///
+    /// When a `MigrationQueue` is attached, this durably `XADD`s `product`
+    /// onto `products:migrate` and returns `Ok(true)` once the queue has
+    /// accepted it -- the actual migration outcome arrives later via the
+    /// background task spawned in `initialize`, which is what updates
+    /// `successful_inserts`/`failed_inserts`. Without a queue, this falls
+    /// back to the synchronous subprocess/HTTP transport call, checked out
+    /// through `worker_pool` (see `with_worker_pool`) when one is configured
+    /// so at most `max_size` of these are ever in flight at once.
     pub async fn migrate_product(&self, product: &ProductData) -> Result<bool> {
         if !self.is_initialized.load(Ordering::Relaxed) {
             return Err(anyhow::anyhow!("GoIntegration not initialized"));
         }
-        
+
         self.go_calls.fetch_add(1, Ordering::Relaxed);
-        
+
+        if let Some(queue) = &self.queue {
+            queue.enqueue(product).await?;
+            return Ok(true);
+        }
+
         let json_payload = self.generate_product_json(product);
-        let command = "migrate-product";
-        
-        match self.execute_go_with_json(command, json_payload).await {
-            Ok(exit_code) => {
-                if exit_code == 0 {
+
+        let call_result = match &self.worker_pool {
+            Some(pool) => {
+                let worker = pool.get(self.pool_checkout_timeout).await?;
+                worker.call("migrate-product", Some(json_payload)).await
+            }
+            None => self.transport.call("migrate-product", Some(json_payload)).await,
+        };
+
+        match call_result {
+            Ok(output) => match GoResponse::decode(&output.body) {
+                Ok(response) if response.success => {
                     self.successful_inserts.fetch_add(1, Ordering::Relaxed);
                     Ok(true)
-                } else {
+                }
+                Ok(response) => {
                     self.failed_inserts.fetch_add(1, Ordering::Relaxed);
+                    if let Some(error) = &response.error {
+                        error!("❌ Go component rejected migration of {}: {}", product.name, error);
+                    }
                     Ok(false)
                 }
-            }
+                Err(e) => {
+                    // The Go binary didn't return a structured response (an
+                    // older binary, say); fall back to the transport's own
+                    // exit-code signal rather than treating this as failure.
+                    warn!("⚠️ Could not decode migrate-product response, falling back to exit code: {}", e);
+                    if output.exit_code == 0 {
+                        self.successful_inserts.fetch_add(1, Ordering::Relaxed);
+                        Ok(true)
+                    } else {
+                        self.failed_inserts.fetch_add(1, Ordering::Relaxed);
+                        Ok(false)
+                    }
+                }
+            },
             Err(e) => {
                 self.failed_inserts.fetch_add(1, Ordering::Relaxed);
                 error!("❌ Error migrating product {}: {}", product.name, e);
@@ -108,32 +656,81 @@ impl GoIntegration {
             }
         }
     }
-    
+
+    /// Migrate many products with bounded parallelism, reporting per-item
+    /// success/failure instead of aborting the whole run on one bad product.
+    ///
+    /// At most `concurrency` `migrate_product` calls are in flight at once
+    /// (`concurrency == 0` is treated as 1). An empty slice returns an empty
+    /// report without touching the transport.
+    pub async fn migrate_products_batch(&self, products: &[ProductData], concurrency: usize) -> BatchReport {
+        use futures::stream::{self, StreamExt};
+
+        if products.is_empty() {
+            return BatchReport::default();
+        }
+
+        let concurrency = concurrency.max(1);
+
+        let outcomes = stream::iter(products.iter())
+            .map(|product| async move {
+                let outcome = self.migrate_product(product).await;
+                (product.slug.clone(), outcome)
+            })
+            .buffer_unordered(concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut report = BatchReport::default();
+        for (slug, outcome) in outcomes {
+            match outcome {
+                // With a `MigrationQueue` attached, `Ok(true)` only means the
+                // product was durably enqueued, not that it migrated --
+                // route it to `queued` instead of `succeeded` so callers
+                // don't mistake "accepted for later processing" for "done".
+                Ok(true) if self.queue.is_some() => report.queued.push(slug),
+                Ok(true) => report.succeeded.push(slug),
+                Ok(false) => report
+                    .failed
+                    .push((slug, GoError::Failed("migration rejected by Go component".to_string()))),
+                Err(e) => report.failed.push((slug, GoError::Failed(e.to_string()))),
+            }
+        }
+
+        report
+    }
+
     /// Get approved products from temporary table via Go component (approval_status = 1)
     pub async fn get_accepted_products(&self) -> Result<Vec<ProductData>> {
         if !self.is_initialized.load(Ordering::Relaxed) {
             return Err(anyhow::anyhow!("GoIntegration not initialized"));
         }
-        
+
         self.go_calls.fetch_add(1, Ordering::Relaxed);
-        
-        match self.execute_go_command("get-approved-products").await {
-            Ok(exit_code) => {
-                if exit_code == 0 {
-                    // In a real implementation, you would parse the output
-                    // For now, return empty vector
-                    Ok(Vec::new())
-                } else {
-                    Err(anyhow::anyhow!("Go command failed with exit code: {}", exit_code))
-                }
-            }
+
+        let output = match self.transport.call("get-approved-products", None).await {
+            Ok(output) => output,
             Err(e) => {
                 error!("❌ Error getting approved products: {}", e);
-                Err(e)
+                return Err(e);
             }
+        };
+
+        let response = GoResponse::decode(&output.body).map_err(|e| anyhow::anyhow!(e))?;
+        if !response.success {
+            let message = response
+                .error
+                .unwrap_or_else(|| "Go component reported failure".to_string());
+            return Err(anyhow::anyhow!(message));
+        }
+
+        match response.get("data.products") {
+            serde_json::Value::Null => Ok(Vec::new()),
+            products => serde_json::from_value(products)
+                .map_err(|e| anyhow::anyhow!("failed to parse data.products: {}", e)),
         }
     }
-    
+
     /// Check if product exists in main table via Go component
     pub async fn check_product_exists(
         &self,
@@ -145,53 +742,70 @@ impl GoIntegration {
         if !self.is_initialized.load(Ordering::Relaxed) {
             return Err(anyhow::anyhow!("GoIntegration not initialized"));
         }
-        
+
         self.go_calls.fetch_add(1, Ordering::Relaxed);
-        
-        let args = format!("check-product --name {} --brand {} --flavor {} --year {}", 
-                          name, brand, flavor, year);
-        
-        match self.execute_go_command(&args).await {
-            Ok(exit_code) => Ok(exit_code == 0),
+
+        let payload = serde_json::json!({
+            "name": name,
+            "brand": brand,
+            "flavor": flavor,
+            "year": year,
+        })
+        .to_string();
+
+        match self.transport.call("check-product", Some(payload)).await {
+            Ok(output) => Ok(output.exit_code == 0),
             Err(e) => {
                 error!("❌ Error checking product existence: {}", e);
                 Err(e)
             }
         }
     }
-    
+
     /// Check if brand exists via Go component
     pub async fn check_brand_exists(&self, brand_name: &str) -> Result<bool> {
         if !self.is_initialized.load(Ordering::Relaxed) {
             return Err(anyhow::anyhow!("GoIntegration not initialized"));
         }
-        
+
         self.go_calls.fetch_add(1, Ordering::Relaxed);
-        
-        let args = format!("check-brand --name {}", brand_name);
-        
-        match self.execute_go_command(&args).await {
-            Ok(exit_code) => Ok(exit_code == 0),
+
+        let payload = serde_json::json!({ "name": brand_name }).to_string();
+
+        match self.transport.call("check-brand", Some(payload)).await {
+            Ok(output) => Ok(output.exit_code == 0),
             Err(e) => {
                 error!("❌ Error checking brand existence: {}", e);
                 Err(e)
             }
         }
     }
-    
+
     /// Verify Go component is working
     pub async fn verify_go_component(&self) -> Result<bool> {
-        match self.execute_go_command("verify").await {
-            Ok(exit_code) => Ok(exit_code == 0),
+        match self.transport.call("verify", None).await {
+            Ok(output) => Ok(output.exit_code == 0),
             Err(e) => {
                 error!("❌ Go component verification failed: {}", e);
                 Ok(false)
             }
         }
     }
-    
+
     /// Get Go integration statistics
     pub async fn get_go_stats(&self) -> GoStats {
+        let (last_scheduled_run, consecutive_failures) = match &self.scheduler {
+            Some(scheduler) => (scheduler.last_run().await, scheduler.consecutive_failures()),
+            None => (None, 0),
+        };
+
+        let (queue_pending, queue_reclaimed) = match &self.queue {
+            Some(queue) => (queue.pending_count(), queue.reclaimed_count()),
+            None => (0, 0),
+        };
+
+        let live_events_connected = self.live_events.as_ref().map(|bridge| bridge.is_connected());
+
         GoStats {
             successful_inserts: self.successful_inserts.load(Ordering::Relaxed),
             failed_inserts: self.failed_inserts.load(Ordering::Relaxed),
@@ -200,35 +814,74 @@ impl GoIntegration {
             go_binary_path: self.go_supabase_binary.clone(),
             working_directory: self.go_working_directory.clone(),
             is_initialized: self.is_initialized.load(Ordering::Relaxed),
+            transport: self.transport.name().to_string(),
+            last_scheduled_run,
+            consecutive_failures,
+            queue_pending,
+            queue_reclaimed,
+            live_events_connected,
+            pool_status: self.worker_pool.as_ref().map(|pool| pool.status()),
+            buffered_count: self.buffered_count().await,
+            last_flush_time: self.last_flush_time().await,
         }
     }
     
-    /// Execute Go binary with command and arguments
-    pub async fn execute_go_command(&self, args: &str) -> Result<i32> {
+    /// Execute the Go binary with pre-split arguments, passed straight to
+    /// `Command::args` -- no shell involved, so an argument containing
+    /// spaces or quotes (e.g. a product name) reaches the child exactly as
+    /// given instead of being re-split on whitespace.
+    pub async fn execute_go_command(&self, args: &[&str]) -> Result<i32> {
         let output = Command::new(&self.go_supabase_binary)
-            .args(args.split_whitespace())
+            .args(args)
             .current_dir(&self.go_working_directory)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?
             .wait_with_output()?;
-        
+
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             error!("Go command failed: {}", stderr);
         }
-        
+
         Ok(output.status.code().unwrap_or(-1))
     }
-    
-    /// Execute Go binary with JSON payload
-    async fn execute_go_with_json(&self, command: &str, json_payload: String) -> Result<i32> {
-        let escaped_json = self.escape_json_for_shell(&json_payload);
-        let args = format!("{} --json '{}'", command, escaped_json);
-        
-        self.execute_go_command(&args).await
+
+    /// Execute the Go binary with `args`, writing `json_payload` to the
+    /// child's stdin instead of interpolating it into argv. This avoids both
+    /// a shell-quoting injection class of bugs and argv length limits.
+    /// `migrate_product` does not use this method: it goes through the
+    /// configured `GoTransport`, and `SubprocessTransport` passes the payload
+    /// as a `--json` argument.
+    pub async fn execute_go_with_stdin(&self, args: &[&str], json_payload: &str) -> Result<i32> {
+        use std::io::Write;
+
+        let mut child = Command::new(&self.go_supabase_binary)
+            .args(args)
+            .current_dir(&self.go_working_directory)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        {
+            let stdin = child
+                .stdin
+                .as_mut()
+                .ok_or_else(|| anyhow::anyhow!("failed to open child stdin"))?;
+            stdin.write_all(json_payload.as_bytes())?;
+        }
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!("Go command failed: {}", stderr);
+        }
+
+        Ok(output.status.code().unwrap_or(-1))
     }
-    
+
     /// Check if Go binary exists and is executable
     pub async fn check_go_binary(&self) -> Result<bool> {
         match Command::new(&self.go_supabase_binary)
@@ -241,11 +894,6 @@ impl GoIntegration {
         }
     }
     
-    /// Escape JSON string for shell execution
-    fn escape_json_for_shell(&self, json: &str) -> String {
-        json.replace('\'', "'\"'\"'")
-    }
-    
     /// Generate JSON payload for single product
     fn generate_product_json(&self, product: &ProductData) -> String {
         serde_json::to_string(product).unwrap_or_else(|_| "{}".to_string())
@@ -257,18 +905,101 @@ impl GoIntegration {
     }
     
     /// Parse Go component response
+    ///
+    /// Back-compat bool shim: routes through `GoResponse::decode` and returns
+    /// `resp.success`. A response that fails to decode is treated as `false`,
+    /// not a substring match on the word "success".
     pub fn parse_go_response(&self, response: &str) -> bool {
-        // Simple response parsing - in a real implementation, you'd parse JSON
-        response.contains("success") || response.contains("ok")
+        GoResponse::decode(response)
+            .map(|resp| resp.success)
+            .unwrap_or(false)
     }
     
     /// Shutdown the Go integration
     pub async fn shutdown(&self) -> Result<()> {
         info!("🔧 Shutting down GoIntegration...");
-        
+
+        // Drain anything still sitting in the batch buffer before the
+        // background flusher is stopped, so no enqueued product is lost.
+        if let Err(e) = self.flush().await {
+            error!("❌ Error flushing batch buffer during shutdown: {}", e);
+        }
+
         self.is_initialized.store(false, Ordering::Relaxed);
-        
+
         info!("✅ GoIntegration shut down");
         Ok(())
     }
 }
+
+/// Send `batch` in one `migrate-products-batch` transport call, then fan the
+/// per-item outcome (`data.results.#.success`, if the Go component reports
+/// one) back into `successful_inserts`/`failed_inserts`; `batch_operations`
+/// and `last_flush_time` are updated once per flush that actually reaches
+/// the transport.
+///
+/// A transient failure to even reach the transport (the call itself
+/// returning `Err`, e.g. the Go binary couldn't be spawned) re-queues
+/// `batch` at the front of `batch_buffer` instead of counting it as failed,
+/// so the next flush retries it ahead of anything enqueued since. A
+/// response that decodes but reports failure is a real outcome, not a
+/// transient one, and is still counted as failed.
+async fn flush_batch(
+    transport: &Arc<dyn GoTransport>,
+    batch: Vec<ProductData>,
+    batch_buffer: &Arc<tokio::sync::Mutex<Vec<ProductData>>>,
+    successful_inserts: &Arc<AtomicU64>,
+    failed_inserts: &Arc<AtomicU64>,
+    batch_operations: &Arc<AtomicU64>,
+    last_flush_time: &Arc<tokio::sync::RwLock<Option<DateTime<Utc>>>>,
+) {
+    if batch.is_empty() {
+        return;
+    }
+
+    let payload = serde_json::to_string(&batch).unwrap_or_else(|_| "[]".to_string());
+
+    let output = match transport.call("migrate-products-batch", Some(payload)).await {
+        Ok(output) => output,
+        Err(e) => {
+            error!(
+                "❌ Batch migration call failed for {} product(s), re-queuing for retry: {}",
+                batch.len(),
+                e
+            );
+            batch_buffer.lock().await.splice(0..0, batch);
+            return;
+        }
+    };
+
+    batch_operations.fetch_add(1, Ordering::Relaxed);
+    *last_flush_time.write().await = Some(Utc::now());
+
+    let response = match GoResponse::decode(&output.body) {
+        Ok(response) => response,
+        Err(e) => {
+            warn!(
+                "⚠️ Could not decode batch migration response, assuming all {} failed: {}",
+                batch.len(),
+                e
+            );
+            failed_inserts.fetch_add(batch.len() as u64, Ordering::Relaxed);
+            return;
+        }
+    };
+
+    match response.get("data.results.#.success").as_array() {
+        Some(outcomes) => {
+            let succeeded = outcomes.iter().filter(|v| v.as_bool() == Some(true)).count() as u64;
+            successful_inserts.fetch_add(succeeded, Ordering::Relaxed);
+            failed_inserts.fetch_add(batch.len() as u64 - succeeded, Ordering::Relaxed);
+        }
+        // No per-item breakdown -- fall back to the batch-level outcome.
+        None if response.success => {
+            successful_inserts.fetch_add(batch.len() as u64, Ordering::Relaxed);
+        }
+        None => {
+            failed_inserts.fetch_add(batch.len() as u64, Ordering::Relaxed);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Bounded concurrent-checkout pool in front of `GoIntegration`'s transport,
+//! modeled on deadpool's `Manager`/`Pool` split.
+//!
+//! `SubprocessTransport` already spawns the Go binary fresh per `call()` --
+//! there is no persistent-subprocess protocol for a literal "pool of live
+//! processes" to hold onto between checkouts. Instead this pool bounds how
+//! many transport calls may be in flight at once: `Pool::get` blocks until
+//! fewer than `max_size` `PooledWorker`s are checked out, and `status()`
+//! reports occupancy so callers can detect saturation.
+
+use anyhow::Result;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+
+use crate::transport::{GoTransport, TransportOutput};
+
+/// A checked-out handle onto the shared `GoTransport`. Issuing a call through
+/// a `GoWorker` is identical to calling the transport directly -- the value
+/// this type adds is the bounded checkout around it, not a distinct
+/// execution path.
+pub struct GoWorker {
+    transport: Arc<dyn GoTransport>,
+}
+
+impl GoWorker {
+    /// Issue `command`/`json_payload` through this worker's transport. See
+    /// `GoTransport::call`.
+    pub async fn call(&self, command: &str, json_payload: Option<String>) -> Result<TransportOutput> {
+        self.transport.call(command, json_payload).await
+    }
+}
+
+/// Creates and health-checks `GoWorker`s for `Pool`.
+struct GoWorkerManager {
+    transport: Arc<dyn GoTransport>,
+}
+
+impl GoWorkerManager {
+    fn new(transport: Arc<dyn GoTransport>) -> Self {
+        Self { transport }
+    }
+
+    fn create(&self) -> GoWorker {
+        GoWorker {
+            transport: self.transport.clone(),
+        }
+    }
+
+    /// Health-check `worker` before handing it back out of the idle list, the
+    /// same way `GoIntegration::verify_go_component` does. `false` means the
+    /// worker should be discarded in favor of a freshly created one.
+    async fn recycle(&self, worker: &GoWorker) -> bool {
+        matches!(worker.call("verify", None).await, Ok(output) if output.exit_code == 0)
+    }
+}
+
+/// Snapshot of `Pool` occupancy, surfaced through `GoStats::pool_status` so
+/// concurrency tests can assert saturation behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PoolStatus {
+    /// Configured `max_size` -- the maximum number of checkouts allowed at once.
+    pub size: usize,
+    /// Checkouts that could proceed immediately, i.e. `size` minus what's
+    /// currently checked out.
+    pub available: usize,
+    /// Callers currently blocked in `Pool::get`, waiting for a permit.
+    pub waiting: usize,
+}
+
+/// Bounded pool of `GoWorker` checkouts over a shared `GoTransport`.
+pub struct Pool {
+    manager: GoWorkerManager,
+    idle: Arc<Mutex<Vec<GoWorker>>>,
+    semaphore: Arc<Semaphore>,
+    max_size: usize,
+    waiting: Arc<AtomicUsize>,
+}
+
+impl Pool {
+    /// Build a pool bounded at `max_size` concurrent checkouts (`0` is
+    /// treated as `1`) over `transport`.
+    pub fn new(transport: Arc<dyn GoTransport>, max_size: usize) -> Self {
+        let max_size = max_size.max(1);
+        Self {
+            manager: GoWorkerManager::new(transport),
+            idle: Arc::new(Mutex::new(Vec::new())),
+            semaphore: Arc::new(Semaphore::new(max_size)),
+            max_size,
+            waiting: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Check out a worker, waiting up to `timeout` for the pool to have room
+    /// if it's currently saturated at `max_size`. An idle worker is
+    /// re-validated via `recycle` before being handed out; one that fails its
+    /// health check is discarded and replaced with a freshly created worker.
+    pub async fn get(&self, timeout: Duration) -> Result<PooledWorker> {
+        self.waiting.fetch_add(1, Ordering::Relaxed);
+        let acquired = tokio::time::timeout(timeout, self.semaphore.clone().acquire_owned()).await;
+        self.waiting.fetch_sub(1, Ordering::Relaxed);
+
+        let permit = acquired
+            .map_err(|_| anyhow::anyhow!("timed out waiting for a Go worker"))?
+            .expect("Pool's semaphore is never closed while the Pool itself is alive");
+
+        let mut idle = self.idle.lock().await;
+        while let Some(worker) = idle.pop() {
+            if self.manager.recycle(&worker).await {
+                return Ok(PooledWorker {
+                    worker: Some(worker),
+                    idle: self.idle.clone(),
+                    _permit: permit,
+                });
+            }
+            warn!("discarding Go worker that failed its recycle health-check");
+        }
+        drop(idle);
+
+        Ok(PooledWorker {
+            worker: Some(self.manager.create()),
+            idle: self.idle.clone(),
+            _permit: permit,
+        })
+    }
+
+    /// Current occupancy snapshot. See `PoolStatus`.
+    pub fn status(&self) -> PoolStatus {
+        PoolStatus {
+            size: self.max_size,
+            available: self.semaphore.available_permits(),
+            waiting: self.waiting.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// RAII checkout returned by `Pool::get`. Dropping it returns the worker to
+/// the pool's idle list (to be re-validated on its next checkout) and
+/// releases its semaphore permit, making room for the next waiter.
+pub struct PooledWorker {
+    worker: Option<GoWorker>,
+    idle: Arc<Mutex<Vec<GoWorker>>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl std::ops::Deref for PooledWorker {
+    type Target = GoWorker;
+
+    fn deref(&self) -> &GoWorker {
+        self.worker.as_ref().expect("worker taken only by Drop")
+    }
+}
+
+impl Drop for PooledWorker {
+    fn drop(&mut self) {
+        let Some(worker) = self.worker.take() else { return };
+        let idle = self.idle.clone();
+        tokio::spawn(async move {
+            idle.lock().await.push(worker);
+        });
+    }
+}
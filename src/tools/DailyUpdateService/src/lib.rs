@@ -1,23 +1,44 @@
 use anyhow::Result;
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{info, error, warn};
+use uuid::Uuid;
 
+pub mod auth;
+pub mod cache_backing;
 pub mod cache_manager;
+pub mod cli;
+pub mod db;
 pub mod go_integration;
+pub mod go_worker_pool;
 pub mod config;
+pub mod live_events;
+pub mod migrations;
+pub mod pending_queue;
+pub mod queue;
+pub mod scheduler;
+pub mod scraper;
+pub mod transport;
+pub mod watcher;
+pub mod worker;
 
+use auth::{Reviewer, ReviewerRegistry};
 use cache_manager::CacheManager;
+use db::{PostgresProductStore, ProductStore};
 use go_integration::GoIntegration;
 use config::ServiceConfig;
+use pending_queue::{PendingUpdateQueue, UpdateOutcome};
+use scraper::{RetailerScraper, ScraperRunner};
+use worker::{Worker, WorkerManager, WorkerState};
 
 /// Product data structure for temporary products processing (matches products table schema)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductData {
-    pub id: Option<i32>,
-    pub brand_id: Option<i32>,
+    pub id: Uuid,
+    pub brand_id: Option<Uuid>,
     pub category: String, // product_category enum
     pub name: String,
     pub slug: String,
@@ -50,7 +71,9 @@ impl ProductData {
     ) -> Self {
         let now = Utc::now();
         Self {
-            id: None,
+            // v7 is time-sortable, so ids created around the same time stay
+            // roughly index-friendly even though they're generated client-side.
+            id: Uuid::now_v7(),
             brand_id: None,
             category,
             name,
@@ -101,6 +124,44 @@ pub struct ServiceStats {
     pub total_denied: u64,
     pub cache_stats: cache_manager::CacheStats,
     pub go_stats: go_integration::GoStats,
+    pub workers: Vec<worker::WorkerInfo>,
+    pub update_control: UpdateControlState,
+    /// Entries in the durable pending-update queue still awaiting a
+    /// finalized outcome (see `pending_queue` module).
+    pub pending_queue_depth: u64,
+    /// Highest `update_id` with a durably recorded outcome, if any have
+    /// finished yet.
+    pub highest_processed_update_id: Option<u64>,
+    /// Average of `workers[].occupancy_rate` across every registered
+    /// worker, so an operator can tell at a glance whether background
+    /// processing is saturated without inspecting `workers` itself. `0.0`
+    /// when no workers are registered.
+    pub average_worker_occupancy: f64,
+}
+
+/// Commands accepted by `UpdateWorker` over its control channel, letting an
+/// operator pause/resume/retrigger product migration without stopping the
+/// service via `should_stop`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateCommand {
+    /// Clear a pause (same effect as `Resume`).
+    Start,
+    /// Stop processing the hourly update until `Resume`/`Start`.
+    Pause,
+    /// Clear a pause set by `Pause`.
+    Resume,
+    /// Run an hourly update immediately, without waiting for the next check.
+    TriggerNow,
+    /// Change the tranquility throttle (see `UpdateConfig::tranquility`).
+    SetTranquility(u32),
+}
+
+/// Pause/tranquility state as last observed by `UpdateWorker`, mirrored here
+/// so `get_service_stats` doesn't need to reach into the worker's channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateControlState {
+    pub paused: bool,
+    pub tranquility: u32,
 }
 
 /// DailyUpdateService V2 - Modular architecture with temporary products system
@@ -118,7 +179,20 @@ pub struct DailyUpdateServiceV2 {
     // Component managers
     pub cache_manager: Arc<CacheManager>,
     pub go_integration: Arc<GoIntegration>,
-    
+
+    // Registry of pluggable background workers (see `worker` module); the
+    // hourly update loop is registered here by `start()`, with cache reset,
+    // product migration, etc. expected to become independent workers later.
+    pub worker_manager: Arc<WorkerManager>,
+
+    // Sender half of `UpdateWorker`'s control channel, set by `start()` once
+    // the worker (and its receiver) exist; `None` before the service starts.
+    update_commands: Arc<RwLock<Option<tokio::sync::mpsc::Sender<UpdateCommand>>>>,
+
+    // Mirror of `UpdateWorker`'s pause/tranquility state, written by the
+    // worker as it processes commands and read by `get_service_stats`.
+    update_control: Arc<RwLock<UpdateControlState>>,
+
     // Service state
     pub is_running: Arc<RwLock<bool>>,
     pub should_stop: Arc<RwLock<bool>>,
@@ -133,14 +207,45 @@ pub struct DailyUpdateServiceV2 {
     
     // Configuration
     pub config: ServiceConfig,
+
+    // Persistence (set during `initialize` when `config.database_url` is configured)
+    pub product_store: Arc<RwLock<Option<Arc<dyn ProductStore>>>>,
+
+    // Durable, crash-safe queue of accepted products awaiting migration (see
+    // `pending_queue` module); opened during `initialize` under
+    // `config.base_directory`.
+    pub pending_queue: Arc<RwLock<Option<Arc<PendingUpdateQueue>>>>,
+
+    // Retailer scrapers registered via `register_scraper`, run on a schedule
+    // from `start()` when `config.scraper_config.enabled` and a product
+    // store are both present.
+    pub scrapers: Arc<RwLock<Vec<Arc<dyn RetailerScraper>>>>,
+
+    // Reviewer accounts registered via `register_reviewer`, consulted by
+    // `approve_product`/`reject_product` before touching the product store.
+    pub reviewer_registry: Arc<RwLock<ReviewerRegistry>>,
 }
 
 impl DailyUpdateServiceV2 {
     /// Create a new DailyUpdateServiceV2 instance
     pub fn new(config: ServiceConfig) -> Self {
         Self {
-            cache_manager: Arc::new(CacheManager::new()),
-            go_integration: Arc::new(GoIntegration::new()),
+            cache_manager: Arc::new(
+                CacheManager::new()
+                    .with_max_capacity(config.cache_config.max_capacity)
+                    .with_ttl_seconds(config.cache_config.ttl_seconds)
+                    .with_idle_seconds(config.cache_config.idle_seconds),
+            ),
+            go_integration: Arc::new(GoIntegration::new().with_worker_pool(
+                config.go_config.pool_max_size,
+                std::time::Duration::from_secs(config.go_config.command_timeout),
+            )),
+            worker_manager: Arc::new(WorkerManager::new()),
+            update_commands: Arc::new(RwLock::new(None)),
+            update_control: Arc::new(RwLock::new(UpdateControlState {
+                paused: false,
+                tranquility: config.update_config.tranquility,
+            })),
             is_running: Arc::new(RwLock::new(false)),
             should_stop: Arc::new(RwLock::new(false)),
             last_update_time: Arc::new(RwLock::new(Utc::now())),
@@ -148,13 +253,115 @@ impl DailyUpdateServiceV2 {
             total_accepted: Arc::new(RwLock::new(0)),
             total_denied: Arc::new(RwLock::new(0)),
             config,
+            product_store: Arc::new(RwLock::new(None)),
+            pending_queue: Arc::new(RwLock::new(None)),
+            scrapers: Arc::new(RwLock::new(Vec::new())),
+            reviewer_registry: Arc::new(RwLock::new(ReviewerRegistry::new())),
         }
     }
-    
+
+    /// Register a retailer scraper to be run on the scheduled scraping pass
+    /// started by `start()`. Has no effect on a pass already in flight.
+    pub async fn register_scraper(&self, scraper: Arc<dyn RetailerScraper>) {
+        self.scrapers.write().await.push(scraper);
+    }
+
+    /// Register a reviewer account that can subsequently authenticate
+    /// through `approve_product`/`reject_product`.
+    pub async fn register_reviewer(&self, reviewer: Reviewer) {
+        self.reviewer_registry.write().await.register(reviewer);
+    }
+
+    /// Authenticate `username`/`password` and approve the pending product
+    /// with this slug, stamping `reviewed_by`/`reviewed_at`.
+    pub async fn approve_product(&self, slug: &str, username: &str, password: &str) -> Result<()> {
+        let registry = self.reviewer_registry.read().await;
+        let reviewer = registry
+            .authenticate(username, password)
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let store = self.product_store.read().await;
+        let store = store
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("no product store configured"))?;
+
+        store.approve(slug, reviewer).await
+    }
+
+    /// Authenticate `username`/`password` and reject the pending product
+    /// with this slug, requiring a non-empty `rejection_reason`.
+    pub async fn reject_product(
+        &self,
+        slug: &str,
+        username: &str,
+        password: &str,
+        rejection_reason: &str,
+    ) -> Result<()> {
+        let registry = self.reviewer_registry.read().await;
+        let reviewer = registry
+            .authenticate(username, password)
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let store = self.product_store.read().await;
+        let store = store
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("no product store configured"))?;
+
+        store.reject(slug, reviewer, rejection_reason).await
+    }
+
+    /// Pause the hourly update loop mid-run, without stopping the service.
+    pub async fn pause_updates(&self) -> Result<()> {
+        self.send_update_command(UpdateCommand::Pause).await
+    }
+
+    /// Resume a paused hourly update loop.
+    pub async fn resume_updates(&self) -> Result<()> {
+        self.send_update_command(UpdateCommand::Resume).await
+    }
+
+    /// Run an hourly update immediately, without waiting for the next check.
+    pub async fn trigger_update_now(&self) -> Result<()> {
+        self.send_update_command(UpdateCommand::TriggerNow).await
+    }
+
+    /// Change the tranquility throttle applied between batches while
+    /// draining the accepted-products backlog (see `UpdateConfig::tranquility`).
+    pub async fn set_tranquility(&self, tranquility: u32) -> Result<()> {
+        self.send_update_command(UpdateCommand::SetTranquility(tranquility)).await
+    }
+
+    /// Send `command` to the running `UpdateWorker`'s control channel.
+    /// Errors if the service hasn't been `start()`-ed yet.
+    async fn send_update_command(&self, command: UpdateCommand) -> Result<()> {
+        let commands = self.update_commands.read().await;
+        let sender = commands
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("update loop is not running"))?;
+        sender
+            .send(command)
+            .await
+            .map_err(|_| anyhow::anyhow!("update loop command channel closed"))
+    }
+
     /// Initialize the service with all components
     pub async fn initialize(&self) -> Result<()> {
         info!("🚀 Initializing DailyUpdateServiceV2...");
-        
+
+        if let Some(database_url) = &self.config.database_url {
+            let store = PostgresProductStore::connect(database_url).await?;
+            *self.product_store.write().await = Some(Arc::new(store));
+            info!("✅ Connected to product store");
+        }
+
+        let pending_queue = PendingUpdateQueue::open(&self.config.base_directory)?;
+        info!(
+            "✅ Opened durable pending-update queue ({} entr{} awaiting replay)",
+            pending_queue.pending_depth(),
+            if pending_queue.pending_depth() == 1 { "y" } else { "ies" }
+        );
+        *self.pending_queue.write().await = Some(Arc::new(pending_queue));
+
         if !self.initialize_components().await? {
             error!("❌ Failed to initialize components");
             return Err(anyhow::anyhow!("Failed to initialize components"));
@@ -179,12 +386,88 @@ impl DailyUpdateServiceV2 {
         *should_stop = false;
         drop(should_stop);
         
-        // Start the update task (runs every hour)
-        let service_clone = self.clone_for_task();
-        tokio::spawn(async move {
-            service_clone.update_task().await;
-        });
-        
+        // Register the hourly update loop as a supervised worker, wired to
+        // a control channel so an operator can pause/resume/retrigger it
+        // without stopping the whole service.
+        let (command_tx, command_rx) = tokio::sync::mpsc::channel(32);
+        *self.update_commands.write().await = Some(command_tx);
+
+        let update_worker = UpdateWorker {
+            cache_manager: self.cache_manager.clone(),
+            go_integration: self.go_integration.clone(),
+            last_update_time: self.last_update_time.clone(),
+            total_processed: self.total_processed.clone(),
+            total_accepted: self.total_accepted.clone(),
+            total_denied: self.total_denied.clone(),
+            commands: command_rx,
+            control: self.update_control.clone(),
+            trigger_now_pending: false,
+            max_batch_size: self.config.update_config.max_batch_size,
+            batch_concurrency: self.config.update_config.batch_concurrency,
+            pending_queue: self.pending_queue.clone(),
+        };
+        self.worker_manager.register(Box::new(update_worker)).await;
+
+        // Finalize a pending-queue entry once its migration-queue outcome is
+        // actually known, rather than on mere enqueue success (see
+        // `UpdateWorker::process_accepted_products`'s handling of
+        // `BatchReport::queued`). No-op if `go_integration` never had a
+        // `MigrationQueue` attached -- the listener is simply never notified.
+        {
+            let pending_queue = self.pending_queue.clone();
+            let total_accepted = self.total_accepted.clone();
+            let total_denied = self.total_denied.clone();
+            self.go_integration
+                .register_migration_result_listener(move |slug, success, error| {
+                    let pending_queue = pending_queue.clone();
+                    let total_accepted = total_accepted.clone();
+                    let total_denied = total_denied.clone();
+                    tokio::spawn(async move {
+                        let Some(queue) = pending_queue.read().await.clone() else {
+                            return;
+                        };
+                        let update_id = match queue.find_pending_by_slug(&slug) {
+                            Ok(Some(id)) => id,
+                            Ok(None) => {
+                                warn!("⚠️ No pending entry found for migration result: {}", slug);
+                                return;
+                            }
+                            Err(e) => {
+                                error!("❌ Error looking up pending entry for {}: {}", slug, e);
+                                return;
+                            }
+                        };
+
+                        let outcome = if success {
+                            info!("✅ Migrated queued product: {}", slug);
+                            *total_accepted.write().await += 1;
+                            UpdateOutcome::Accepted
+                        } else {
+                            if let Some(error) = &error {
+                                error!("❌ Queued migration failed for {}: {}", slug, error);
+                            }
+                            *total_denied.write().await += 1;
+                            UpdateOutcome::Denied
+                        };
+
+                        if let Err(e) = queue.finalize(update_id, outcome) {
+                            error!("❌ Failed to finalize queued migration result for {}: {}", slug, e);
+                        }
+                    });
+                })
+                .await;
+        }
+
+        if self.config.scraper_config.enabled {
+            let product_store = self.product_store.clone();
+            let scrapers = self.scrapers.clone();
+            let should_stop = self.should_stop.clone();
+            let scraper_config = self.config.scraper_config.clone();
+            tokio::spawn(async move {
+                Self::scraper_task(product_store, scrapers, should_stop, scraper_config).await;
+            });
+        }
+
         info!("✅ DailyUpdateServiceV2 started - hourly updates enabled");
         Ok(())
     }
@@ -202,23 +485,41 @@ impl DailyUpdateServiceV2 {
         let mut should_stop = self.should_stop.write().await;
         *should_stop = true;
         drop(should_stop);
-        
+
+        self.worker_manager.stop().await;
+
         // Wait a bit for tasks to finish
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-        
+
+        // Drain whatever `GoIntegration::enqueue` has buffered so far, so
+        // nothing enqueued is left waiting on the background flusher's next
+        // size or linger trigger. `stop` does not shut `GoIntegration` down,
+        // so that flusher keeps running for as long as it stays initialized.
+        if let Err(e) = self.go_integration.flush().await {
+            error!("❌ Error flushing Go batch buffer during stop: {}", e);
+        }
+
         let mut is_running = self.is_running.write().await;
         *is_running = false;
-        
+
         info!("✅ DailyUpdateServiceV2 stopped");
         Ok(())
     }
-    
+
     /// Force trigger hourly update (for testing)
     pub async fn force_hourly_update(&self) -> Result<()> {
         info!("🔄 Force triggering hourly update...");
         self.perform_hourly_update().await?;
         Ok(())
     }
+
+    /// Immediately flush `GoIntegration`'s batching buffer (see
+    /// `GoIntegration::enqueue`/`flush`), without waiting for its
+    /// `batch_size`/`batch_linger` thresholds. Exposed alongside
+    /// `force_hourly_update` as another force path for tests/operators.
+    pub async fn flush_now(&self) -> Result<()> {
+        self.go_integration.flush().await
+    }
     
     /// Get accepted products from temporary table for processing
     pub async fn get_accepted_products(&self) -> Result<Vec<ProductData>> {
@@ -232,7 +533,20 @@ impl DailyUpdateServiceV2 {
         let total_processed = *self.total_processed.read().await;
         let total_accepted = *self.total_accepted.read().await;
         let total_denied = *self.total_denied.read().await;
-        
+
+        let (pending_queue_depth, highest_processed_update_id) =
+            match self.pending_queue.read().await.clone() {
+                Some(queue) => (queue.pending_depth(), queue.highest_processed_id()),
+                None => (0, None),
+            };
+
+        let workers = self.worker_manager.list().await;
+        let average_worker_occupancy = if workers.is_empty() {
+            0.0
+        } else {
+            workers.iter().map(|w| w.occupancy_rate).sum::<f64>() / workers.len() as f64
+        };
+
         ServiceStats {
             is_running,
             last_update_time,
@@ -241,6 +555,11 @@ impl DailyUpdateServiceV2 {
             total_denied,
             cache_stats: self.cache_manager.get_cache_stats().await,
             go_stats: self.go_integration.get_go_stats().await,
+            workers,
+            update_control: self.update_control.read().await.clone(),
+            pending_queue_depth,
+            highest_processed_update_id,
+            average_worker_occupancy,
         }
     }
     
@@ -258,12 +577,48 @@ impl DailyUpdateServiceV2 {
         }
     }
     
+    /// Periodically run every registered scraper against the product store,
+    /// skipping a pass (with a warning) when no store is configured. Mirrors
+    /// `update_task`'s poll-and-sleep shutdown pattern.
+    async fn scraper_task(
+        product_store: Arc<RwLock<Option<Arc<dyn ProductStore>>>>,
+        scrapers: Arc<RwLock<Vec<Arc<dyn RetailerScraper>>>>,
+        should_stop: Arc<RwLock<bool>>,
+        scraper_config: config::ScraperConfig,
+    ) {
+        let interval = scheduler::parse_interval(&scraper_config.interval)
+            .unwrap_or(std::time::Duration::from_secs(6 * 3600));
+        info!("🕷️ Scraper task started - running every {:?}", interval);
+
+        loop {
+            if *should_stop.read().await {
+                break;
+            }
+
+            match product_store.read().await.clone() {
+                Some(store) => {
+                    let scrapers = scrapers.read().await.clone();
+                    if scrapers.is_empty() {
+                        info!("ℹ️ No scrapers registered - skipping pass");
+                    } else if let Err(e) = ScraperRunner::new(scrapers, store).run_once().await {
+                        error!("❌ Scraper pass failed: {}", e);
+                    }
+                }
+                None => warn!("⚠️ Scraper task skipped: no product store configured"),
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+
+        info!("🕷️ Scraper task stopped");
+    }
+
     /// Initialize all component managers
     async fn initialize_components(&self) -> Result<bool> {
         info!("🔧 Initializing components...");
         
         // Initialize Cache Manager
-        if !self.cache_manager.initialize().await? {
+        if !self.cache_manager.initialize(&self.config.base_directory).await? {
             error!("❌ Failed to initialize CacheManager");
             return Ok(false);
         }
@@ -351,6 +706,230 @@ impl DailyUpdateServiceV2 {
     }
 }
 
+/// How often `UpdateWorker::step` re-runs, both to check whether an hour has
+/// passed since the last update and to drain its control channel -- short
+/// enough that `pause_updates`/`resume_updates`/etc. take effect promptly.
+const UPDATE_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Checks periodically whether an hour has passed since the last update and,
+/// if so, processes accepted products and resets caches; also drains its
+/// control channel so an operator can pause/resume/retrigger it or change its
+/// tranquility throttle without stopping the whole service. Registered with
+/// `WorkerManager` by `start()` -- the first worker implementing the
+/// `Worker` trait, with cache reset and product migration expected to split
+/// into their own independent workers later.
+struct UpdateWorker {
+    cache_manager: Arc<CacheManager>,
+    go_integration: Arc<GoIntegration>,
+    last_update_time: Arc<RwLock<DateTime<Utc>>>,
+    total_processed: Arc<RwLock<u64>>,
+    total_accepted: Arc<RwLock<u64>>,
+    total_denied: Arc<RwLock<u64>>,
+
+    // Control channel: `drain_commands` applies every command queued since
+    // the last `step` before deciding what to do next.
+    commands: tokio::sync::mpsc::Receiver<UpdateCommand>,
+    control: Arc<RwLock<UpdateControlState>>,
+    // Set by a `TriggerNow` command; consumed (and cleared) by the next `step`.
+    trigger_now_pending: bool,
+
+    // Batching: `process_accepted_products` migrates at most `max_batch_size`
+    // products per `migrate_products_batch` call, each with up to
+    // `batch_concurrency` in-flight `migrate_product` calls.
+    max_batch_size: usize,
+    batch_concurrency: usize,
+
+    // Durable pending-update queue (see `pending_queue` module);
+    // `process_accepted_products` enqueues newly accepted products here and
+    // migrates strictly in `update_id` order, finalizing each before moving
+    // to the next so a crash mid-run replays, rather than loses, anything
+    // still pending. An entry `migrate_products_batch` reports as merely
+    // `queued` (durably enqueued onto a `MigrationQueue`, outcome not yet
+    // known) is left pending instead -- see the migration-result listener
+    // registered in `start()`, which finalizes it once the real outcome
+    // arrives.
+    pending_queue: Arc<RwLock<Option<Arc<PendingUpdateQueue>>>>,
+}
+
+impl UpdateWorker {
+    /// Apply every command queued on `commands` since the last call, without
+    /// blocking if there are none.
+    async fn drain_commands(&mut self) {
+        while let Ok(command) = self.commands.try_recv() {
+            match command {
+                UpdateCommand::Start | UpdateCommand::Resume => {
+                    self.control.write().await.paused = false;
+                }
+                UpdateCommand::Pause => {
+                    self.control.write().await.paused = true;
+                }
+                UpdateCommand::TriggerNow => {
+                    self.trigger_now_pending = true;
+                }
+                UpdateCommand::SetTranquility(tranquility) => {
+                    self.control.write().await.tranquility = tranquility;
+                }
+            }
+        }
+    }
+
+    /// Perform the actual hourly update
+    async fn perform_hourly_update(&self) -> Result<()> {
+        info!("🔄 Starting hourly update process...");
+
+        // 1. Process accepted products from temporary table
+        self.process_accepted_products().await?;
+
+        // 2. Reset caches (excluding AdminCache - only on system outage)
+        self.cache_manager.perform_daily_cache_reset().await?;
+
+        // Update last update time
+        let mut last_update_time = self.last_update_time.write().await;
+        *last_update_time = Utc::now();
+
+        info!("✅ Hourly update completed successfully");
+        Ok(())
+    }
+
+    /// Process accepted products from temporary table
+    async fn process_accepted_products(&self) -> Result<()> {
+        info!("📋 Processing accepted products from temporary table...");
+
+        let queue = self
+            .pending_queue
+            .read()
+            .await
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("pending update queue not initialized"))?;
+
+        // Durably assign each newly accepted product the next monotonic
+        // update_id before migrating anything -- entries already pending
+        // from an earlier poll (or left over from a crash) aren't
+        // re-enqueued, since `pending()` below already includes them.
+        for product in &self.go_integration.get_accepted_products().await? {
+            queue.enqueue_if_absent(product)?;
+        }
+
+        let pending = queue.pending()?;
+
+        if pending.is_empty() {
+            info!("ℹ️ No accepted products to process");
+            return Ok(());
+        }
+
+        info!("📦 Found {} accepted products to process", pending.len());
+
+        let mut processed_count = 0;
+        let mut accepted_count = 0;
+        let mut denied_count = 0;
+
+        // Migrate strictly in update_id order, in bounded batches, throttled
+        // by `tranquility` so a large backlog can be drained slowly instead
+        // of saturating the Go integration/database. An entry is only
+        // removed from the durable queue once its outcome is finalized.
+        for chunk in pending.chunks(self.max_batch_size.max(1)) {
+            let started = std::time::Instant::now();
+
+            let products: Vec<ProductData> = chunk.iter().map(|(_, product)| product.clone()).collect();
+
+            // `migrate_products_batch` is genuinely async all the way down
+            // (HTTP/Redis/subprocess calls awaited via `tokio::process`),
+            // so just await it directly -- wrapping it in `spawn_blocking`
+            // would only tie up a blocking-pool thread for the duration of
+            // I/O that already yields to the scheduler on its own.
+            let report = self
+                .go_integration
+                .migrate_products_batch(&products, self.batch_concurrency)
+                .await;
+
+            let succeeded: std::collections::HashSet<&str> =
+                report.succeeded.iter().map(String::as_str).collect();
+            let failed: std::collections::HashMap<&str, &go_integration::GoError> =
+                report.failed.iter().map(|(slug, error)| (slug.as_str(), error)).collect();
+            let queued: std::collections::HashSet<&str> =
+                report.queued.iter().map(String::as_str).collect();
+
+            for (update_id, product) in chunk {
+                if queued.contains(product.slug.as_str()) {
+                    // Durably `XADD`ed onto the Go migration queue, but not
+                    // confirmed -- leave this entry pending. The listener
+                    // registered in `start()` finalizes it once the real
+                    // outcome arrives off `products:migrate:results`, so we
+                    // never mark a merely-enqueued product Accepted.
+                    info!("📨 Queued product for migration: {}", product.slug);
+                } else if succeeded.contains(product.slug.as_str()) {
+                    accepted_count += 1;
+                    info!("✅ Migrated product: {}", product.slug);
+                    queue.finalize(*update_id, UpdateOutcome::Accepted)?;
+                } else if let Some(error) = failed.get(product.slug.as_str()) {
+                    denied_count += 1;
+                    error!("❌ Failed to migrate product {}: {}", product.slug, error);
+                    queue.finalize(*update_id, UpdateOutcome::Denied)?;
+                } else {
+                    denied_count += 1;
+                    error!("❌ No outcome reported for product {}", product.slug);
+                    queue.finalize(*update_id, UpdateOutcome::Error)?;
+                }
+            }
+            processed_count += chunk.len() as u64;
+
+            let tranquility = self.control.read().await.tranquility;
+            if tranquility > 0 {
+                tokio::time::sleep(started.elapsed() * tranquility).await;
+            }
+        }
+
+        // Update statistics
+        {
+            let mut total_processed = self.total_processed.write().await;
+            let mut total_accepted = self.total_accepted.write().await;
+            let mut total_denied = self.total_denied.write().await;
+
+            *total_processed += processed_count;
+            *total_accepted += accepted_count;
+            *total_denied += denied_count;
+        }
+
+        info!("✅ Processed {} accepted products", processed_count);
+        Ok(())
+    }
+
+    /// Check if it's time for hourly update
+    async fn is_time_for_hourly_update(&self) -> bool {
+        let now = Utc::now();
+        let last_update = *self.last_update_time.read().await;
+
+        // Check if at least 1 hour has passed
+        now.signed_duration_since(last_update).num_hours() >= 1
+    }
+}
+
+#[async_trait]
+impl Worker for UpdateWorker {
+    fn name(&self) -> &str {
+        "hourly-update"
+    }
+
+    async fn step(&mut self) -> Result<WorkerState> {
+        self.drain_commands().await;
+
+        if self.control.read().await.paused {
+            return Ok(WorkerState::Idle(UPDATE_CHECK_INTERVAL));
+        }
+
+        if self.trigger_now_pending {
+            self.trigger_now_pending = false;
+            info!("⏰ Update triggered via control channel - processing...");
+            self.perform_hourly_update().await?;
+        } else if self.is_time_for_hourly_update().await {
+            info!("⏰ Time for hourly update - processing...");
+            self.perform_hourly_update().await?;
+        }
+
+        Ok(WorkerState::Idle(UPDATE_CHECK_INTERVAL))
+    }
+}
+
 /// Task-specific service data for async operations
 pub struct DailyUpdateServiceTask {
     pub cache_manager: Arc<CacheManager>,
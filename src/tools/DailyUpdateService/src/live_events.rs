@@ -0,0 +1,117 @@
+use anyhow::Result;
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::header::AUTHORIZATION;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{error, info, warn};
+
+/// How long to wait before retrying a dropped or failed WebSocket connection.
+const RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// One migration's outcome, pushed by the Go component over its WebSocket
+/// event stream as soon as it happens, rather than being inferred from a
+/// subprocess exit code or an HTTP response body.
+#[derive(Debug, Clone, Deserialize)]
+struct MigrationEvent {
+    slug: String,
+    success: bool,
+    error: Option<String>,
+}
+
+/// Subscribes to the Go component's live migration-event WebSocket and
+/// updates `successful_inserts`/`failed_inserts` as events arrive, so the
+/// `HttpTransport` path gets real-time counters instead of only learning
+/// outcomes when `migrate_product`/`migrate_products_batch` is called.
+///
+/// Reconnects with a fixed delay on disconnect for as long as `run` is
+/// polled; `is_connected` reflects the current connection state for
+/// `GoStats`.
+pub struct MigrationEventBridge {
+    ws_url: String,
+    token: Option<String>,
+    successful_inserts: Arc<AtomicU64>,
+    failed_inserts: Arc<AtomicU64>,
+    connected: Arc<AtomicBool>,
+}
+
+impl MigrationEventBridge {
+    pub fn new(
+        ws_url: impl Into<String>,
+        token: Option<String>,
+        successful_inserts: Arc<AtomicU64>,
+        failed_inserts: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            ws_url: ws_url.into(),
+            token,
+            successful_inserts,
+            failed_inserts,
+            connected: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Whether the WebSocket connection is currently up.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Connect and process events until `is_initialized` is no longer true,
+    /// reconnecting with `RECONNECT_DELAY` between attempts on disconnect.
+    pub async fn run(&self, is_initialized: Arc<AtomicBool>) {
+        while is_initialized.load(Ordering::Relaxed) {
+            if let Err(e) = self.connect_and_listen().await {
+                warn!("⚠️ Migration event bridge disconnected: {}", e);
+            }
+            self.connected.store(false, Ordering::Relaxed);
+
+            if !is_initialized.load(Ordering::Relaxed) {
+                break;
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    async fn connect_and_listen(&self) -> Result<()> {
+        let mut request = self.ws_url.as_str().into_client_request()?;
+        if let Some(token) = &self.token {
+            request
+                .headers_mut()
+                .insert(AUTHORIZATION, format!("Bearer {}", token).parse()?);
+        }
+
+        let (ws_stream, _) = tokio_tungstenite::connect_async(request).await?;
+        self.connected.store(true, Ordering::Relaxed);
+        info!("🔌 Connected to Go component migration event stream");
+
+        let (mut write, mut read) = ws_stream.split();
+
+        while let Some(message) = read.next().await {
+            match message? {
+                Message::Text(text) => self.handle_event(&text),
+                Message::Ping(payload) => write.send(Message::Pong(payload)).await?,
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_event(&self, text: &str) {
+        match serde_json::from_str::<MigrationEvent>(text) {
+            Ok(event) if event.success => {
+                self.successful_inserts.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(event) => {
+                self.failed_inserts.fetch_add(1, Ordering::Relaxed);
+                if let Some(err) = &event.error {
+                    error!("❌ Migration failed for {}: {}", event.slug, err);
+                }
+            }
+            Err(e) => warn!("⚠️ Dropping unparseable migration event: {}", e),
+        }
+    }
+}
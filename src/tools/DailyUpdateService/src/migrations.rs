@@ -0,0 +1,217 @@
+use sqlx::any::{AnyPool, AnyPoolOptions};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tracing::info;
+
+/// Errors produced while discovering or applying schema migrations for the
+/// Go component's database.
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    #[error("unsupported database scheme in connection URL: {0}")]
+    UnsupportedScheme(String),
+    #[error("migration file name '{0}' does not match V<version>__<name>.sql")]
+    InvalidFileName(String),
+    #[error("checksum mismatch for already-applied migration version {version}: on-disk content changed since it was applied")]
+    ChecksumMismatch { version: i64 },
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A single discovered `.sql` migration file, e.g. `V2__add_flavor.sql`.
+#[derive(Debug, Clone)]
+pub struct MigrationFile {
+    pub version: i64,
+    pub name: String,
+    pub path: PathBuf,
+    pub checksum: u32,
+}
+
+/// Database backend selected from the connection URL scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl Backend {
+    fn detect(database_url: &str) -> Result<Self, MigrationError> {
+        if database_url.starts_with("postgres://") || database_url.starts_with("postgresql://") {
+            Ok(Backend::Postgres)
+        } else if database_url.starts_with("mysql://") {
+            Ok(Backend::MySql)
+        } else if database_url.starts_with("sqlite://") || database_url.starts_with("sqlite:") {
+            Ok(Backend::Sqlite)
+        } else {
+            Err(MigrationError::UnsupportedScheme(database_url.to_string()))
+        }
+    }
+
+    fn create_tracking_table_sql(self) -> &'static str {
+        match self {
+            Backend::Postgres => {
+                "CREATE TABLE IF NOT EXISTS _migrations (\
+                    version BIGINT PRIMARY KEY, \
+                    name TEXT NOT NULL, \
+                    checksum BIGINT NOT NULL, \
+                    applied_at TIMESTAMPTZ NOT NULL DEFAULT now())"
+            }
+            Backend::MySql => {
+                "CREATE TABLE IF NOT EXISTS _migrations (\
+                    version BIGINT PRIMARY KEY, \
+                    name VARCHAR(255) NOT NULL, \
+                    checksum BIGINT NOT NULL, \
+                    applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP)"
+            }
+            // Used by the `TestDb` harness's embedded-SQLite fallback when
+            // `DATABASE_URL` isn't set.
+            Backend::Sqlite => {
+                "CREATE TABLE IF NOT EXISTS _migrations (\
+                    version INTEGER PRIMARY KEY, \
+                    name TEXT NOT NULL, \
+                    checksum INTEGER NOT NULL, \
+                    applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP)"
+            }
+        }
+    }
+}
+
+/// Parse a migration file name into `(version, name)`, e.g.
+/// `V12__add_flavor.sql` -> `(12, "add_flavor")`.
+fn parse_migration_filename(file_name: &str) -> Option<(i64, String)> {
+    let stem = file_name.strip_suffix(".sql")?;
+    let (version_part, name) = stem.split_once("__")?;
+    let version = version_part.strip_prefix('V')?.parse::<i64>().ok()?;
+    Some((version, name.to_string()))
+}
+
+fn crc32_of(bytes: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(bytes);
+    hasher.finalize()
+}
+
+/// Discovers ordered `.sql` migration files, records applied versions in a
+/// `_migrations` tracking table, and applies pending migrations in version
+/// order inside a transaction. Supports PostgreSQL and MySQL, selected by
+/// the connection URL scheme.
+pub struct MigrationRunner {
+    migrations_dir: PathBuf,
+    database_url: String,
+}
+
+impl MigrationRunner {
+    pub fn new(migrations_dir: impl Into<PathBuf>, database_url: impl Into<String>) -> Self {
+        Self {
+            migrations_dir: migrations_dir.into(),
+            database_url: database_url.into(),
+        }
+    }
+
+    /// Discover `.sql` files in `migrations_dir`, sorted by version.
+    pub fn discover_migrations(&self) -> Result<Vec<MigrationFile>, MigrationError> {
+        self.discover_migrations_in(&self.migrations_dir)
+    }
+
+    fn discover_migrations_in(&self, dir: &Path) -> Result<Vec<MigrationFile>, MigrationError> {
+        let mut files = Vec::new();
+
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("sql") {
+                continue;
+            }
+
+            let file_name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default()
+                .to_string();
+            let (version, name) = parse_migration_filename(&file_name)
+                .ok_or_else(|| MigrationError::InvalidFileName(file_name.clone()))?;
+            let checksum = crc32_of(&std::fs::read(&path)?);
+
+            files.push(MigrationFile { version, name, path, checksum });
+        }
+
+        files.sort_by_key(|f| f.version);
+        Ok(files)
+    }
+
+    /// Apply any pending migrations in version order, inside a transaction
+    /// per file. Returns the number of newly-applied migrations.
+    ///
+    /// If a migration already recorded in `_migrations` has a checksum that
+    /// no longer matches the on-disk file, this aborts with
+    /// `MigrationError::ChecksumMismatch` rather than silently re-running it.
+    /// On a mid-migration failure the transaction for that file is rolled
+    /// back and the connection dropped, so `_migrations` never records a
+    /// half-applied version.
+    pub async fn run(&self) -> Result<u64, MigrationError> {
+        let backend = Backend::detect(&self.database_url)?;
+        let pool: AnyPool = AnyPoolOptions::new()
+            .max_connections(1)
+            .connect(&self.database_url)
+            .await?;
+
+        let result = self.run_with_pool(&pool, backend).await;
+        pool.close().await;
+        result
+    }
+
+    async fn run_with_pool(&self, pool: &AnyPool, backend: Backend) -> Result<u64, MigrationError> {
+        sqlx::query(backend.create_tracking_table_sql())
+            .execute(pool)
+            .await?;
+
+        let applied: Vec<(i64, i64)> =
+            sqlx::query_as("SELECT version, checksum FROM _migrations ORDER BY version")
+                .fetch_all(pool)
+                .await?;
+        let applied_checksums: HashMap<i64, i64> = applied.into_iter().collect();
+
+        let files = self.discover_migrations()?;
+        let mut newly_applied = 0u64;
+
+        for file in &files {
+            if let Some(recorded_checksum) = applied_checksums.get(&file.version) {
+                if *recorded_checksum != file.checksum as i64 {
+                    return Err(MigrationError::ChecksumMismatch { version: file.version });
+                }
+                continue;
+            }
+
+            info!("🔧 Applying migration V{}__{}.sql", file.version, file.name);
+
+            let sql = std::fs::read_to_string(&file.path)?;
+            let mut tx = pool.begin().await?;
+
+            // On any error below, `tx` is dropped without being committed,
+            // which rolls back the partial migration and drops the connection.
+            sqlx::query(&sql).execute(&mut *tx).await?;
+            // `sqlx::Any` passes placeholders through unchanged, and Postgres
+            // only accepts numbered ones.
+            let record_sql = match backend {
+                Backend::Postgres => "INSERT INTO _migrations (version, name, checksum) VALUES ($1, $2, $3)",
+                Backend::MySql | Backend::Sqlite => {
+                    "INSERT INTO _migrations (version, name, checksum) VALUES (?, ?, ?)"
+                }
+            };
+            sqlx::query(record_sql)
+                .bind(file.version)
+                .bind(&file.name)
+                .bind(file.checksum as i64)
+                .execute(&mut *tx)
+                .await?;
+
+            tx.commit().await?;
+            newly_applied += 1;
+        }
+
+        Ok(newly_applied)
+    }
+}
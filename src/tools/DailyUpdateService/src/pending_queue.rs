@@ -0,0 +1,134 @@
+//! Durable, sequentially-ordered queue of accepted products awaiting
+//! migration, backed by a `sled` database rooted at
+//! `ServiceConfig::base_directory`.
+//!
+//! Responsibilities:
+//! - Assign each enqueued product the next globally monotonic `update_id`
+//! - Hold it in `pending_queue` (keyed by big-endian `update_id`, so the
+//!   pending set iterates in ascending order) until its outcome is durably
+//!   recorded
+//! - Record every finished update's outcome and timestamp in `processed`
+//!   (keyed the same way), so the processed history is replayable
+//! - Survive a crash/restart: an entry is only removed after `finalize`
+//!   durably writes its outcome, so whatever is still in `pending_queue` on
+//!   reopen simply iterates back out via `pending()` for replay
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::ProductData;
+
+/// Final outcome recorded for a processed update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpdateOutcome {
+    Accepted,
+    Denied,
+    Error,
+}
+
+/// A durably-recorded finished update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessedUpdate {
+    pub update_id: u64,
+    pub outcome: UpdateOutcome,
+    pub finished_at: DateTime<Utc>,
+}
+
+/// Durable FIFO of accepted products awaiting migration, plus a log of
+/// finished updates, backed by two `sled` trees in the same database so the
+/// pending set and the processed history can each be iterated cheaply in
+/// `update_id` order.
+pub struct PendingUpdateQueue {
+    db: sled::Db,
+    pending: sled::Tree,
+    processed: sled::Tree,
+}
+
+impl PendingUpdateQueue {
+    /// Open (creating if necessary) the durable queue under
+    /// `<base_directory>/pending_updates`.
+    pub fn open(base_directory: impl AsRef<Path>) -> Result<Self> {
+        let db = sled::open(base_directory.as_ref().join("pending_updates"))?;
+        let pending = db.open_tree("pending_queue")?;
+        let processed = db.open_tree("processed")?;
+        Ok(Self {
+            db,
+            pending,
+            processed,
+        })
+    }
+
+    /// Assign the next monotonic `update_id` to `product` and durably
+    /// enqueue it, unless a pending entry for the same slug already exists
+    /// (e.g. from an earlier, not-yet-finalized poll).
+    pub fn enqueue_if_absent(&self, product: &ProductData) -> Result<Option<u64>> {
+        for entry in self.pending.iter() {
+            let (_, value) = entry?;
+            let existing: ProductData = serde_json::from_slice(&value)?;
+            if existing.slug == product.slug {
+                return Ok(None);
+            }
+        }
+
+        let update_id = self.db.generate_id()?;
+        self.pending
+            .insert(update_id.to_be_bytes(), serde_json::to_vec(product)?)?;
+        Ok(Some(update_id))
+    }
+
+    /// Find the pending entry for `slug`, if any -- used to map a migration
+    /// result keyed by slug (e.g. from a `MigrationQueue` result listener)
+    /// back to the `update_id` `finalize` needs.
+    pub fn find_pending_by_slug(&self, slug: &str) -> Result<Option<u64>> {
+        for entry in self.pending.iter() {
+            let (key, value) = entry?;
+            let existing: ProductData = serde_json::from_slice(&value)?;
+            if existing.slug == slug {
+                return Ok(Some(u64::from_be_bytes(key.as_ref().try_into()?)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Every pending entry, in ascending `update_id` order -- both freshly
+    /// enqueued work and anything left over from before a crash/restart.
+    pub fn pending(&self) -> Result<Vec<(u64, ProductData)>> {
+        let mut out = Vec::with_capacity(self.pending.len());
+        for entry in self.pending.iter() {
+            let (key, value) = entry?;
+            let update_id = u64::from_be_bytes(key.as_ref().try_into()?);
+            out.push((update_id, serde_json::from_slice(&value)?));
+        }
+        Ok(out)
+    }
+
+    /// Durably record `update_id`'s outcome, then remove it from the pending
+    /// set. Written in this order so a crash between the two still leaves
+    /// the entry recoverable (re-processed, not lost) on the next `pending()`.
+    pub fn finalize(&self, update_id: u64, outcome: UpdateOutcome) -> Result<()> {
+        let record = ProcessedUpdate {
+            update_id,
+            outcome,
+            finished_at: Utc::now(),
+        };
+        self.processed
+            .insert(update_id.to_be_bytes(), serde_json::to_vec(&record)?)?;
+        self.pending.remove(update_id.to_be_bytes())?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    /// Number of entries still awaiting a finalized outcome.
+    pub fn pending_depth(&self) -> u64 {
+        self.pending.len() as u64
+    }
+
+    /// Highest `update_id` with a durably recorded outcome, if any have
+    /// finished yet.
+    pub fn highest_processed_id(&self) -> Option<u64> {
+        let (key, _) = self.processed.last().ok().flatten()?;
+        Some(u64::from_be_bytes(key.as_ref().try_into().ok()?))
+    }
+}
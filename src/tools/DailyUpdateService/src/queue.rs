@@ -0,0 +1,197 @@
+use anyhow::Result;
+use redis::streams::{StreamMaxlen, StreamReadOptions, StreamReadReply};
+use redis::AsyncCommands;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::ProductData;
+
+const MIGRATE_STREAM: &str = "products:migrate";
+const RESULTS_STREAM: &str = "products:migrate:results";
+const CONSUMER_GROUP: &str = "migrators";
+const MAX_STREAM_LEN: usize = 100_000;
+
+/// How long a results-stream entry may sit unacknowledged on a consumer
+/// before `reclaim_stale` hands it to a different (live) consumer.
+const CLAIM_IDLE: Duration = Duration::from_secs(30);
+
+/// One consumer's outcome for a single `products:migrate` entry, written
+/// back by the Go worker onto `products:migrate:results`. Public so callers
+/// of `poll_results` (e.g. `GoIntegration`'s migration-result listeners) can
+/// learn which product actually finished, not just how many did.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MigrationOutcome {
+    pub slug: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Redis Streams-backed replacement for one-shot Go binary spawns per
+/// product: `enqueue` `XADD`s each `ProductData` onto `products:migrate`
+/// (trimmed with `MAXLEN ~` so it can't grow unbounded) for a Go
+/// consumer-group worker to process. `poll_results` reads outcomes back via
+/// `XREADGROUP` on `products:migrate:results`, updating
+/// `successful_inserts`/`failed_inserts`, returning each parsed
+/// `MigrationOutcome`, and `XACK`ing each entry only after that update has
+/// happened -- never before, so a crash mid-poll leaves the entry
+/// redeliverable instead of silently dropped.
+/// `reclaim_stale` runs `XAUTOCLAIM` to recover entries left behind by a
+/// consumer that died mid-processing.
+pub struct MigrationQueue {
+    client: redis::Client,
+    // Unique per instance so multiple Rust processes can write/consume
+    // concurrently without colliding on pending-entry ownership.
+    consumer_name: String,
+    successful_inserts: Arc<AtomicU64>,
+    failed_inserts: Arc<AtomicU64>,
+    pending: Arc<AtomicU64>,
+    reclaimed: Arc<AtomicU64>,
+}
+
+impl MigrationQueue {
+    /// Connect to Redis and ensure the `migrators` consumer group exists on
+    /// `products:migrate:results` (idempotent -- an already-existing group
+    /// is not an error). Counters are shared with `GoIntegration` so results
+    /// read off the stream land in the same `GoStats` the subprocess/HTTP
+    /// transports report through.
+    pub async fn connect(
+        redis_url: &str,
+        successful_inserts: Arc<AtomicU64>,
+        failed_inserts: Arc<AtomicU64>,
+    ) -> Result<Self> {
+        let client = redis::Client::open(redis_url)?;
+        let mut conn = client.get_multiplexed_async_connection().await?;
+
+        let created: redis::RedisResult<()> = conn
+            .xgroup_create_mkstream(RESULTS_STREAM, CONSUMER_GROUP, "$")
+            .await;
+        if let Err(e) = created {
+            if !e.to_string().contains("BUSYGROUP") {
+                return Err(e.into());
+            }
+        }
+
+        Ok(Self {
+            client,
+            consumer_name: format!("rust-{}", Uuid::now_v7()),
+            successful_inserts,
+            failed_inserts,
+            pending: Arc::new(AtomicU64::new(0)),
+            reclaimed: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// `XADD` `product` onto `products:migrate` for the Go side to pick up.
+    pub async fn enqueue(&self, product: &ProductData) -> Result<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        let payload = serde_json::to_string(product)?;
+
+        conn.xadd_maxlen(
+            MIGRATE_STREAM,
+            StreamMaxlen::Approx(MAX_STREAM_LEN),
+            "*",
+            &[("slug", product.slug.as_str()), ("product", payload.as_str())],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Read up to `count` newly delivered results for this consumer
+    /// (blocking up to `block`), updating `successful_inserts`/
+    /// `failed_inserts` and `XACK`ing each entry. Returns every outcome
+    /// parsed, in delivery order, so a caller (e.g. `GoIntegration`'s
+    /// migration-result listeners) can act on a specific slug's outcome
+    /// rather than just the aggregate counters.
+    pub async fn poll_results(&self, count: usize, block: Duration) -> Result<Vec<MigrationOutcome>> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+
+        let opts = StreamReadOptions::default()
+            .group(CONSUMER_GROUP, &self.consumer_name)
+            .count(count)
+            .block(block.as_millis() as usize);
+
+        let reply: StreamReadReply = conn
+            .xread_options(&[RESULTS_STREAM], &[">"], &opts)
+            .await?;
+
+        let mut outcomes = Vec::new();
+        for stream_key in reply.keys {
+            for entry in stream_key.ids {
+                match parse_result(&entry.map) {
+                    Ok(result) => {
+                        if result.success {
+                            self.successful_inserts.fetch_add(1, Ordering::Relaxed);
+                        } else {
+                            self.failed_inserts.fetch_add(1, Ordering::Relaxed);
+                            if let Some(err) = &result.error {
+                                error!("❌ Migration failed for {}: {}", result.slug, err);
+                            }
+                        }
+                        outcomes.push(result);
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Dropping unparseable migration result {}: {}", entry.id, e);
+                    }
+                }
+
+                // Ack only now that the outcome (or its failure to parse) is
+                // durably reflected in our counters, never before.
+                let _: () = conn.xack(RESULTS_STREAM, CONSUMER_GROUP, &[entry.id.as_str()]).await?;
+            }
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Recover entries idle on a crashed consumer for longer than
+    /// `CLAIM_IDLE`, claiming them for this consumer via `XAUTOCLAIM`, and
+    /// refresh the pending-entry count. Both counts are surfaced through
+    /// `GoStats`.
+    pub async fn reclaim_stale(&self) -> Result<usize> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+
+        let (_cursor, claimed, _deleted): (String, Vec<redis::streams::StreamId>, Vec<String>) = conn
+            .xautoclaim(
+                RESULTS_STREAM,
+                CONSUMER_GROUP,
+                &self.consumer_name,
+                CLAIM_IDLE.as_millis() as i64,
+                "0-0",
+            )
+            .await?;
+        self.reclaimed.fetch_add(claimed.len() as u64, Ordering::Relaxed);
+
+        let pending: redis::streams::StreamPendingCountReply = conn
+            .xpending_count(RESULTS_STREAM, CONSUMER_GROUP, "-", "+", 1000)
+            .await?;
+        self.pending.store(pending.ids.len() as u64, Ordering::Relaxed);
+
+        Ok(claimed.len())
+    }
+
+    /// Number of results-stream entries currently pending acknowledgment,
+    /// as of the last `reclaim_stale` run.
+    pub fn pending_count(&self) -> u64 {
+        self.pending.load(Ordering::Relaxed)
+    }
+
+    /// Total entries this consumer has reclaimed from stale consumers.
+    pub fn reclaimed_count(&self) -> u64 {
+        self.reclaimed.load(Ordering::Relaxed)
+    }
+}
+
+fn parse_result(fields: &HashMap<String, redis::Value>) -> Result<MigrationOutcome> {
+    let raw_value = fields
+        .get("result")
+        .ok_or_else(|| anyhow::anyhow!("missing 'result' field on stream entry"))?;
+    let raw: String = redis::from_redis_value(raw_value)?;
+
+    Ok(serde_json::from_str(&raw)?)
+}
@@ -0,0 +1,181 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info};
+
+/// Smallest interval the scheduler will tick at, regardless of what was
+/// requested, to avoid busy-looping on a misconfigured near-zero interval.
+const MIN_TICK: Duration = Duration::from_secs(1);
+
+/// Errors produced while parsing a human-readable schedule interval.
+#[derive(Debug, Error)]
+pub enum ScheduleError {
+    #[error("invalid schedule interval '{input}': {reason}")]
+    Parse { input: String, reason: String },
+}
+
+/// Parse a human-duration string like "24h", "30m", "90s", or the combined
+/// form "1h30m" (component magnitudes are summed) into a `Duration`.
+pub fn parse_interval(input: &str) -> Result<Duration, ScheduleError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ScheduleError::Parse {
+            input: input.to_string(),
+            reason: "empty interval".to_string(),
+        });
+    }
+
+    let mut total = Duration::ZERO;
+    let mut number = String::new();
+    let mut saw_component = false;
+
+    for ch in trimmed.chars() {
+        if ch.is_ascii_digit() {
+            number.push(ch);
+            continue;
+        }
+
+        if number.is_empty() {
+            return Err(ScheduleError::Parse {
+                input: input.to_string(),
+                reason: format!("expected a number before '{}'", ch),
+            });
+        }
+
+        let magnitude: u64 = number.parse().map_err(|_| ScheduleError::Parse {
+            input: input.to_string(),
+            reason: format!("magnitude '{}' is not a valid number", number),
+        })?;
+        number.clear();
+
+        let component = match ch {
+            's' => Duration::from_secs(magnitude),
+            'm' => Duration::from_secs(magnitude * 60),
+            'h' => Duration::from_secs(magnitude * 3600),
+            'd' => Duration::from_secs(magnitude * 86400),
+            other => {
+                return Err(ScheduleError::Parse {
+                    input: input.to_string(),
+                    reason: format!("unknown unit '{}' (expected s/m/h/d)", other),
+                })
+            }
+        };
+
+        total += component;
+        saw_component = true;
+    }
+
+    if !number.is_empty() {
+        return Err(ScheduleError::Parse {
+            input: input.to_string(),
+            reason: "trailing number with no unit suffix".to_string(),
+        });
+    }
+    if !saw_component {
+        return Err(ScheduleError::Parse {
+            input: input.to_string(),
+            reason: "no valid s/m/h/d component found".to_string(),
+        });
+    }
+
+    Ok(total)
+}
+
+/// How the scheduler catches up on missed ticks (e.g. after the process
+/// was blocked past a tick boundary), mirroring `tokio::time::MissedTickBehavior`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissedTickPolicy {
+    /// Skip missed ticks and resume on the next scheduled boundary.
+    Skip,
+    /// Fire ticks back-to-back to make up for lost time.
+    Burst,
+}
+
+impl From<MissedTickPolicy> for MissedTickBehavior {
+    fn from(policy: MissedTickPolicy) -> Self {
+        match policy {
+            MissedTickPolicy::Skip => MissedTickBehavior::Skip,
+            MissedTickPolicy::Burst => MissedTickBehavior::Burst,
+        }
+    }
+}
+
+/// Drives a recurring async job on a fixed interval parsed from a
+/// human-readable duration string, with missed-tick catch-up behavior and
+/// run/failure bookkeeping for observability.
+pub struct Scheduler {
+    interval: Duration,
+    missed_tick_policy: MissedTickPolicy,
+    last_run: Arc<RwLock<Option<DateTime<Utc>>>>,
+    consecutive_failures: Arc<AtomicU64>,
+}
+
+impl Scheduler {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(MIN_TICK),
+            missed_tick_policy: MissedTickPolicy::Skip,
+            last_run: Arc::new(RwLock::new(None)),
+            consecutive_failures: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Build a scheduler from a human-duration string (see `parse_interval`).
+    pub fn from_human(spec: &str) -> Result<Self, ScheduleError> {
+        Ok(Self::new(parse_interval(spec)?))
+    }
+
+    pub fn with_missed_tick_policy(mut self, policy: MissedTickPolicy) -> Self {
+        self.missed_tick_policy = policy;
+        self
+    }
+
+    pub async fn last_run(&self) -> Option<DateTime<Utc>> {
+        *self.last_run.read().await
+    }
+
+    pub fn consecutive_failures(&self) -> u64 {
+        self.consecutive_failures.load(Ordering::Relaxed)
+    }
+
+    /// Repeatedly invoke `job` on the configured interval until
+    /// `shutdown_signal` resolves.
+    pub async fn run_until<F, Fut>(
+        &self,
+        mut job: F,
+        mut shutdown_signal: tokio::sync::oneshot::Receiver<()>,
+    ) where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(self.missed_tick_policy.into());
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    match job().await {
+                        Ok(()) => {
+                            self.consecutive_failures.store(0, Ordering::Relaxed);
+                            *self.last_run.write().await = Some(Utc::now());
+                        }
+                        Err(e) => {
+                            self.consecutive_failures.fetch_add(1, Ordering::Relaxed);
+                            error!("❌ Scheduled job failed: {}", e);
+                        }
+                    }
+                }
+                _ = &mut shutdown_signal => {
+                    info!("🛑 Scheduler received shutdown signal");
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::db::ProductStore;
+use crate::ProductData;
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Semaphore};
+use tracing::{info, warn};
+
+/// Synthetic `submitted_by` id stamped on products that entered the review
+/// queue via a scraper rather than a human submission.
+pub fn synthetic_submitter(source: &str) -> String {
+    format!("scraper:{}", source)
+}
+
+/// Fetches a retailer's current catalog into pending `ProductData`. Adapters
+/// are expected to go through a `HostThrottle` (see below) for any network
+/// calls so politeness limits are enforced uniformly across retailers.
+#[async_trait]
+pub trait RetailerScraper: Send + Sync {
+    /// Stable source identifier, e.g. a hostname, used for the synthetic
+    /// submitter id and surfaced in logs.
+    fn source(&self) -> &str;
+
+    async fn fetch_catalog(&self) -> Result<Vec<ProductData>>;
+}
+
+/// Per-host politeness limiter shared across scraper adapters: caps
+/// concurrent in-flight requests to a host and enforces a minimum delay
+/// between requests to the same host.
+pub struct HostThrottle {
+    per_host_concurrency: usize,
+    min_delay: Duration,
+    semaphores: Mutex<HashMap<String, Arc<Semaphore>>>,
+    last_request: Mutex<HashMap<String, Instant>>,
+}
+
+impl HostThrottle {
+    pub fn new(per_host_concurrency: usize, min_delay: Duration) -> Self {
+        Self {
+            per_host_concurrency: per_host_concurrency.max(1),
+            min_delay,
+            semaphores: Mutex::new(HashMap::new()),
+            last_request: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn semaphore_for(&self, host: &str) -> Arc<Semaphore> {
+        let mut semaphores = self.semaphores.lock().await;
+        semaphores
+            .entry(host.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(self.per_host_concurrency)))
+            .clone()
+    }
+
+    /// Wait for a free concurrency slot and for the minimum inter-request
+    /// delay to elapse, then return a guard that releases the slot on drop.
+    pub async fn acquire(&self, host: &str) -> HostPermit {
+        let semaphore = self.semaphore_for(host).await;
+        let permit = semaphore
+            .acquire_owned()
+            .await
+            .expect("HostThrottle semaphore is never closed");
+
+        loop {
+            let wait = {
+                let mut last_request = self.last_request.lock().await;
+                match last_request.get(host) {
+                    Some(last) if last.elapsed() < self.min_delay => {
+                        Some(self.min_delay - last.elapsed())
+                    }
+                    _ => {
+                        last_request.insert(host.to_string(), Instant::now());
+                        None
+                    }
+                }
+            };
+
+            match wait {
+                Some(remaining) => tokio::time::sleep(remaining).await,
+                None => break,
+            }
+        }
+
+        HostPermit { _permit: permit }
+    }
+}
+
+/// Held for the duration of a single request; releases the per-host
+/// concurrency slot when dropped.
+pub struct HostPermit {
+    _permit: tokio::sync::OwnedSemaphorePermit,
+}
+
+/// Drives a set of `RetailerScraper`s, persisting newly discovered products
+/// through a `ProductStore` while skipping any slug that's already known.
+pub struct ScraperRunner {
+    scrapers: Vec<Arc<dyn RetailerScraper>>,
+    store: Arc<dyn ProductStore>,
+}
+
+impl ScraperRunner {
+    pub fn new(scrapers: Vec<Arc<dyn RetailerScraper>>, store: Arc<dyn ProductStore>) -> Self {
+        Self { scrapers, store }
+    }
+
+    /// Run every configured scraper once, inserting products whose slug
+    /// isn't already in the store. Returns the number of new products
+    /// inserted; failures in one scraper or one insert don't stop the rest.
+    pub async fn run_once(&self) -> Result<usize> {
+        let mut inserted = 0;
+
+        for scraper in &self.scrapers {
+            let products = match scraper.fetch_catalog().await {
+                Ok(products) => products,
+                Err(e) => {
+                    warn!("⚠️ Scraper '{}' failed to fetch catalog: {}", scraper.source(), e);
+                    continue;
+                }
+            };
+
+            info!("📦 Scraper '{}' returned {} products", scraper.source(), products.len());
+
+            for product in products {
+                match self.store.get_by_slug(&product.slug).await {
+                    Ok(Some(_)) => continue,
+                    Ok(None) => match self.store.insert(&product).await {
+                        Ok(()) => inserted += 1,
+                        Err(e) => warn!("⚠️ Failed to insert scraped product '{}': {}", product.slug, e),
+                    },
+                    Err(e) => warn!("⚠️ Failed to check existing slug '{}': {}", product.slug, e),
+                }
+            }
+        }
+
+        info!("✅ Scraper pass inserted {} new pending products", inserted);
+        Ok(inserted)
+    }
+}
+
+/// One row of a retailer's JSON catalog feed, as fetched by
+/// `JsonCatalogScraper`.
+#[derive(Debug, serde::Deserialize)]
+struct CatalogEntry {
+    name: String,
+    slug: String,
+    category: String,
+    serving_size_g: Option<f64>,
+    servings_per_container: Option<i32>,
+}
+
+/// Scrapes a retailer that exposes its catalog as a single JSON array
+/// endpoint (`GET {catalog_url}` -> `[CatalogEntry, ...]`), going through a
+/// shared `HostThrottle` for politeness.
+pub struct JsonCatalogScraper {
+    source: String,
+    catalog_url: String,
+    host: String,
+    client: reqwest::Client,
+    throttle: Arc<HostThrottle>,
+}
+
+impl JsonCatalogScraper {
+    pub fn new(source: impl Into<String>, catalog_url: impl Into<String>, throttle: Arc<HostThrottle>) -> Result<Self> {
+        let catalog_url = catalog_url.into();
+        let host = reqwest::Url::parse(&catalog_url)?
+            .host_str()
+            .ok_or_else(|| anyhow::anyhow!("catalog URL '{}' has no host", catalog_url))?
+            .to_string();
+
+        Ok(Self {
+            source: source.into(),
+            catalog_url,
+            host,
+            client: reqwest::Client::new(),
+            throttle,
+        })
+    }
+}
+
+#[async_trait]
+impl RetailerScraper for JsonCatalogScraper {
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    async fn fetch_catalog(&self) -> Result<Vec<ProductData>> {
+        let _permit = self.throttle.acquire(&self.host).await;
+
+        let entries: Vec<CatalogEntry> = self
+            .client
+            .get(&self.catalog_url)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        let submitted_by = synthetic_submitter(&self.source);
+        let products = entries
+            .into_iter()
+            .map(|entry| {
+                let mut product =
+                    ProductData::new(entry.name, entry.slug, entry.category, submitted_by.clone());
+                product.serving_size_g = entry.serving_size_g;
+                product.servings_per_container = entry.servings_per_container;
+                product
+            })
+            .collect();
+
+        Ok(products)
+    }
+}
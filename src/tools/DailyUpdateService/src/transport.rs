@@ -0,0 +1,134 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::process::Stdio;
+use tracing::error;
+
+use crate::go_integration::GoResponse;
+
+/// Outcome of a single Go component call, abstracted away from whether it
+/// came back as a process exit code or an HTTP response: `exit_code` is
+/// `0` on success (mirroring process exit-code conventions) and `body` is
+/// the raw stdout/response text for typed decoding via `GoResponse`.
+pub struct TransportOutput {
+    pub exit_code: i32,
+    pub body: String,
+}
+
+/// How `GoIntegration` reaches the Go component. `SubprocessTransport` spawns
+/// the `go-supabase` binary per call; `HttpTransport` talks to a persistent
+/// Go microservice instead, avoiding a process fork on every operation.
+#[async_trait]
+pub trait GoTransport: Send + Sync {
+    async fn call(&self, command: &str, json_payload: Option<String>) -> Result<TransportOutput>;
+
+    /// Short identifier surfaced in `GoStats` (e.g. "subprocess", "http").
+    fn name(&self) -> &'static str;
+}
+
+/// Spawns the Go binary fresh for every call (the original transport).
+pub struct SubprocessTransport {
+    pub binary_path: String,
+    pub working_directory: String,
+}
+
+#[async_trait]
+impl GoTransport for SubprocessTransport {
+    async fn call(&self, command: &str, json_payload: Option<String>) -> Result<TransportOutput> {
+        let mut cmd = tokio::process::Command::new(&self.binary_path);
+        cmd.arg(command).current_dir(&self.working_directory);
+
+        if let Some(json) = &json_payload {
+            cmd.arg("--json").arg(json);
+        }
+
+        // `tokio::process::Command`, not `std::process::Command`: this is an
+        // `async fn` called on every Go subprocess invocation (migrations,
+        // batch fallback, health checks, ...), and a blocking fork/exec/wait
+        // here would stall the whole Tokio worker thread for as long as the
+        // child takes to finish.
+        let output = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?
+            .wait_with_output()
+            .await?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!("Go command failed: {}", stderr);
+        }
+
+        Ok(TransportOutput {
+            exit_code: output.status.code().unwrap_or(-1),
+            body: String::from_utf8_lossy(&output.stdout).to_string(),
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "subprocess"
+    }
+}
+
+/// Fixed User-Agent sent on every `HttpTransport` request.
+const HTTP_USER_AGENT: &str = "daily-update-service/1.0";
+
+/// Talks to a long-running Go service over HTTP instead of spawning a
+/// binary per call. Each command maps to a `POST {base_url}/{command}`
+/// with the JSON payload as the request body.
+pub struct HttpTransport {
+    base_url: String,
+    client: reqwest::Client,
+    token: Option<String>,
+}
+
+impl HttpTransport {
+    pub fn new(base_url: String) -> Self {
+        Self {
+            base_url,
+            client: reqwest::Client::new(),
+            token: None,
+        }
+    }
+
+    /// Same as `new`, but every request carries an `Authorization: Bearer
+    /// <token>` header, for a Go service configured to require one.
+    pub fn with_token(base_url: String, token: String) -> Self {
+        Self {
+            base_url,
+            client: reqwest::Client::new(),
+            token: Some(token),
+        }
+    }
+}
+
+#[async_trait]
+impl GoTransport for HttpTransport {
+    async fn call(&self, command: &str, json_payload: Option<String>) -> Result<TransportOutput> {
+        let url = format!("{}/{}", self.base_url.trim_end_matches('/'), command);
+        let body = json_payload.unwrap_or_else(|| "{}".to_string());
+
+        let mut request = self
+            .client
+            .post(&url)
+            .header(reqwest::header::USER_AGENT, HTTP_USER_AGENT)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.body(body).send().await?;
+
+        let text = response.text().await?;
+        let decoded = GoResponse::decode(&text).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        Ok(TransportOutput {
+            exit_code: if decoded.success { 0 } else { 1 },
+            body: text,
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "http"
+    }
+}
@@ -0,0 +1,175 @@
+use crate::go_integration::GoIntegration;
+use crate::ProductData;
+use anyhow::Result;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+/// A submission file on disk: a `ProductData` plus the flavor/year fields
+/// `GoIntegration::check_product_exists` uses for identity, which aren't
+/// part of the persisted product schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSubmission {
+    #[serde(flatten)]
+    pub product: ProductData,
+    pub flavor: Option<String>,
+    pub year: Option<String>,
+}
+
+impl ProductSubmission {
+    /// Stable content hash over a canonical field ordering (name, slug,
+    /// category, flavor, year) so whitespace and casing differences in the
+    /// submitted JSON don't defeat deduplication.
+    pub fn content_hash(&self) -> String {
+        let canonical = [
+            self.product.name.trim().to_lowercase(),
+            self.product.slug.trim().to_lowercase(),
+            self.product.category.trim().to_lowercase(),
+            self.flavor.as_deref().unwrap_or("").trim().to_lowercase(),
+            self.year.as_deref().unwrap_or("").trim().to_lowercase(),
+        ]
+        .join("\u{1}");
+
+        let mut hasher = Sha256::new();
+        hasher.update(canonical.as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// Watches a submissions directory for new/changed JSON product files and
+/// migrates the parsed products, deduplicating by content hash so an
+/// unchanged resubmission is skipped rather than re-migrated.
+pub struct ProductWatcher {
+    submissions_dir: PathBuf,
+    go_integration: Arc<GoIntegration>,
+    migrated_hashes: Arc<Mutex<HashSet<String>>>,
+    debounce: Duration,
+    dedup_index_path: Option<PathBuf>,
+}
+
+impl ProductWatcher {
+    pub fn new(submissions_dir: impl Into<PathBuf>, go_integration: Arc<GoIntegration>) -> Self {
+        Self {
+            submissions_dir: submissions_dir.into(),
+            go_integration,
+            migrated_hashes: Arc::new(Mutex::new(HashSet::new())),
+            debounce: Duration::from_millis(500),
+            dedup_index_path: None,
+        }
+    }
+
+    /// Collapse a burst of rapid writes to the same file into a single
+    /// migration attempt by waiting this long after the last observed event.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Persist the dedup index to disk, loading any existing entries so a
+    /// restart doesn't re-migrate everything already seen.
+    pub fn with_dedup_index(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if let Ok(contents) = std::fs::read_to_string(&path) {
+            if let Ok(hashes) = serde_json::from_str::<HashSet<String>>(&contents) {
+                *self.migrated_hashes.lock().unwrap() = hashes;
+            }
+        }
+        self.dedup_index_path = Some(path);
+        self
+    }
+
+    fn persist_dedup_index(&self) {
+        if let Some(path) = &self.dedup_index_path {
+            let hashes = self.migrated_hashes.lock().unwrap().clone();
+            if let Ok(json) = serde_json::to_string(&hashes) {
+                let _ = std::fs::write(path, json);
+            }
+        }
+    }
+
+    /// Parse one submission file and migrate it if its content hash hasn't
+    /// been recorded as migrated yet.
+    pub async fn process_file(&self, path: &Path) -> Result<()> {
+        let contents = tokio::fs::read_to_string(path).await?;
+        let submission: ProductSubmission = serde_json::from_str(&contents)?;
+        let hash = submission.content_hash();
+
+        {
+            let mut migrated = self.migrated_hashes.lock().unwrap();
+            if migrated.contains(&hash) {
+                info!("⏭️ Skipping already-migrated submission {:?} (hash {})", path, hash);
+                return Ok(());
+            }
+            migrated.insert(hash.clone());
+        }
+
+        match self.go_integration.migrate_product(&submission.product).await {
+            Ok(true) => {
+                info!("✅ Migrated submission {:?}", path);
+                self.persist_dedup_index();
+                Ok(())
+            }
+            Ok(false) | Err(_) => {
+                // The dispatch happened (go_calls was already bumped inside
+                // migrate_product) but failed; un-mark so a corrected
+                // resubmission of the same file is retried.
+                self.migrated_hashes.lock().unwrap().remove(&hash);
+                Err(anyhow::anyhow!("migration failed for {:?}", path))
+            }
+        }
+    }
+
+    /// Start watching `submissions_dir` for create/modify events until
+    /// `shutdown_signal` resolves.
+    pub async fn run(&self, mut shutdown_signal: tokio::sync::oneshot::Receiver<()>) -> Result<()> {
+        let (tx, mut rx) = mpsc::channel::<PathBuf>(256);
+
+        let mut watcher: RecommendedWatcher =
+            notify::recommended_watcher(move |res: notify::Result<Event>| {
+                if let Ok(event) = res {
+                    if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                        for path in event.paths {
+                            let _ = tx.blocking_send(path);
+                        }
+                    }
+                }
+            })?;
+        watcher.watch(&self.submissions_dir, RecursiveMode::NonRecursive)?;
+
+        let mut pending: HashMap<PathBuf, Instant> = HashMap::new();
+
+        loop {
+            tokio::select! {
+                Some(path) = rx.recv() => {
+                    pending.insert(path, Instant::now());
+                }
+                _ = tokio::time::sleep(Duration::from_millis(50)) => {
+                    let ready: Vec<PathBuf> = pending
+                        .iter()
+                        .filter(|(_, seen)| seen.elapsed() >= self.debounce)
+                        .map(|(path, _)| path.clone())
+                        .collect();
+
+                    for path in ready {
+                        pending.remove(&path);
+                        if let Err(e) = self.process_file(&path).await {
+                            warn!("⚠️ Failed to process submission {:?}: {}", path, e);
+                        }
+                    }
+                }
+                _ = &mut shutdown_signal => {
+                    info!("🛑 ProductWatcher shutting down");
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::{error, info};
+
+/// How long a worker's supervised loop waits before calling `step` again
+/// after it returns an error.
+const RESTART_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Width of the sliding window `occupancy_rate` is computed over: samples
+/// older than this are dropped before each `list()` and each new sample.
+const OCCUPANCY_WINDOW: Duration = Duration::from_secs(300);
+
+/// What a `Worker::step` call accomplished, driving how soon its supervised
+/// loop calls it again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerState {
+    /// Did useful work; call `step` again immediately.
+    Active,
+    /// Nothing to do right now; sleep for the given duration before the next `step`.
+    Idle(Duration),
+    /// Finished for good; the supervisor stops calling `step`.
+    Done,
+}
+
+/// A unit of background work supervised by a `WorkerManager`. Each call to
+/// `step` should do one bounded chunk of work and report what it did via
+/// `WorkerState` rather than looping internally -- the supervisor owns the
+/// loop, the idle sleep, and the error backoff.
+#[async_trait]
+pub trait Worker: Send + Sync {
+    /// Short, stable name surfaced in `WorkerInfo` (e.g. "hourly-update").
+    fn name(&self) -> &str;
+
+    /// Perform one step of work.
+    async fn step(&mut self) -> Result<WorkerState>;
+}
+
+/// Current supervised state of a registered worker, as reported by
+/// `WorkerManager::list`. A worker is `Dead` for good once a step returns
+/// `Done`, and temporarily while backed off after an error -- in the error
+/// case its supervised loop keeps calling `step`, so it returns to
+/// `Active`/`Idle` once a step succeeds again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkerRunState {
+    Active,
+    Idle,
+    Dead,
+}
+
+/// Point-in-time snapshot of one registered worker's supervised state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerInfo {
+    pub name: String,
+    pub state: WorkerRunState,
+    pub iterations: u64,
+    pub last_error: Option<String>,
+    /// Fraction of wall-clock time over the trailing `OCCUPANCY_WINDOW`
+    /// (5 minutes) this worker spent inside `step` rather than idle/backed
+    /// off, in `[0.0, 1.0]`. `0.0` until the window has any samples.
+    pub occupancy_rate: f64,
+}
+
+/// One recorded span of time, used to compute `occupancy_rate` over a
+/// trailing window without keeping unbounded history.
+struct OccupancySample {
+    at: Instant,
+    duration: Duration,
+    active: bool,
+}
+
+/// Sliding-window occupancy tracker shared between a worker's supervised
+/// loop (which records samples) and `WorkerManager::list` (which reads them).
+#[derive(Default)]
+struct Occupancy {
+    samples: VecDeque<OccupancySample>,
+}
+
+impl Occupancy {
+    fn record(&mut self, duration: Duration, active: bool) {
+        let now = Instant::now();
+        self.samples.push_back(OccupancySample {
+            at: now,
+            duration,
+            active,
+        });
+        self.trim(now);
+    }
+
+    fn trim(&mut self, now: Instant) {
+        while let Some(sample) = self.samples.front() {
+            if now.duration_since(sample.at) > OCCUPANCY_WINDOW {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn rate(&mut self) -> f64 {
+        self.trim(Instant::now());
+
+        let mut active_nanos: u128 = 0;
+        let mut total_nanos: u128 = 0;
+        for sample in &self.samples {
+            total_nanos += sample.duration.as_nanos();
+            if sample.active {
+                active_nanos += sample.duration.as_nanos();
+            }
+        }
+
+        if total_nanos == 0 {
+            0.0
+        } else {
+            active_nanos as f64 / total_nanos as f64
+        }
+    }
+}
+
+/// Per-worker bookkeeping shared between its supervised task and `list()`.
+struct WorkerHandle {
+    name: String,
+    state: Arc<RwLock<WorkerRunState>>,
+    iterations: Arc<AtomicU64>,
+    last_error: Arc<RwLock<Option<String>>>,
+    occupancy: Arc<RwLock<Occupancy>>,
+}
+
+/// Owns a set of background workers, each driven by its own supervised loop:
+/// `Active` steps run back-to-back, `Idle(d)` sleeps for `d` before the next
+/// step, an `Err` backs off for `RESTART_BACKOFF` and records the error
+/// before retrying, and `Done` stops the loop for good. Time spent inside
+/// `step` itself counts as occupied; time spent sleeping (idle or backed
+/// off) counts as unoccupied -- see `WorkerInfo::occupancy_rate`.
+#[derive(Default)]
+pub struct WorkerManager {
+    handles: RwLock<Vec<WorkerHandle>>,
+    should_stop: Arc<RwLock<bool>>,
+}
+
+impl WorkerManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `worker` and spawn its supervised loop immediately.
+    pub async fn register(&self, mut worker: Box<dyn Worker>) {
+        let name = worker.name().to_string();
+        let state = Arc::new(RwLock::new(WorkerRunState::Active));
+        let iterations = Arc::new(AtomicU64::new(0));
+        let last_error = Arc::new(RwLock::new(None));
+        let occupancy = Arc::new(RwLock::new(Occupancy::default()));
+
+        self.handles.write().await.push(WorkerHandle {
+            name: name.clone(),
+            state: state.clone(),
+            iterations: iterations.clone(),
+            last_error: last_error.clone(),
+            occupancy: occupancy.clone(),
+        });
+
+        let should_stop = self.should_stop.clone();
+
+        tokio::spawn(async move {
+            info!("🚀 Worker '{}' started", name);
+
+            loop {
+                if *should_stop.read().await {
+                    break;
+                }
+
+                let step_started = Instant::now();
+                let outcome = worker.step().await;
+                occupancy.write().await.record(step_started.elapsed(), true);
+
+                match outcome {
+                    Ok(WorkerState::Active) => {
+                        *state.write().await = WorkerRunState::Active;
+                        iterations.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Ok(WorkerState::Idle(duration)) => {
+                        *state.write().await = WorkerRunState::Idle;
+                        iterations.fetch_add(1, Ordering::Relaxed);
+                        tokio::time::sleep(duration).await;
+                        occupancy.write().await.record(duration, false);
+                    }
+                    Ok(WorkerState::Done) => {
+                        *state.write().await = WorkerRunState::Dead;
+                        info!("✅ Worker '{}' finished", name);
+                        break;
+                    }
+                    Err(e) => {
+                        *state.write().await = WorkerRunState::Dead;
+                        *last_error.write().await = Some(e.to_string());
+                        error!(
+                            "❌ Worker '{}' failed, restarting in {:?}: {}",
+                            name, RESTART_BACKOFF, e
+                        );
+                        tokio::time::sleep(RESTART_BACKOFF).await;
+                        occupancy.write().await.record(RESTART_BACKOFF, false);
+                    }
+                }
+            }
+
+            info!("🛑 Worker '{}' stopped", name);
+        });
+    }
+
+    /// Snapshot the current state of every registered worker.
+    pub async fn list(&self) -> Vec<WorkerInfo> {
+        let handles = self.handles.read().await;
+        let mut infos = Vec::with_capacity(handles.len());
+        for handle in handles.iter() {
+            infos.push(WorkerInfo {
+                name: handle.name.clone(),
+                state: *handle.state.read().await,
+                iterations: handle.iterations.load(Ordering::Relaxed),
+                last_error: handle.last_error.read().await.clone(),
+                occupancy_rate: handle.occupancy.write().await.rate(),
+            });
+        }
+        infos
+    }
+
+    /// Signal every registered worker's supervised loop to stop after its
+    /// current `step`/sleep completes.
+    pub async fn stop(&self) {
+        *self.should_stop.write().await = true;
+    }
+}
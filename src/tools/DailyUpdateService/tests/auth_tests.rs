@@ -0,0 +1,35 @@
+use daily_update_service::auth::{Reviewer, ReviewerRegistry, ReviewerRole};
+
+const TEST_COST: u32 = 4; // cheapest valid bcrypt cost, keeps tests fast
+
+#[test]
+fn test_reviewer_verifies_correct_and_rejects_wrong_password() {
+    let reviewer = Reviewer::new("alice".to_string(), "correct-horse", ReviewerRole::Moderator, TEST_COST).unwrap();
+
+    assert!(reviewer.verify_password("correct-horse").unwrap());
+    assert!(!reviewer.verify_password("wrong-password").unwrap());
+}
+
+#[test]
+fn test_registry_authenticates_registered_reviewer() {
+    let mut registry = ReviewerRegistry::new();
+    registry.register(Reviewer::new("alice".to_string(), "correct-horse", ReviewerRole::Admin, TEST_COST).unwrap());
+
+    let reviewer = registry.authenticate("alice", "correct-horse").unwrap();
+    assert_eq!(reviewer.username, "alice");
+    assert_eq!(reviewer.role, ReviewerRole::Admin);
+}
+
+#[test]
+fn test_registry_rejects_unknown_username() {
+    let registry = ReviewerRegistry::new();
+    assert!(registry.authenticate("nobody", "whatever").is_err());
+}
+
+#[test]
+fn test_registry_rejects_wrong_password() {
+    let mut registry = ReviewerRegistry::new();
+    registry.register(Reviewer::new("alice".to_string(), "correct-horse", ReviewerRole::Moderator, TEST_COST).unwrap());
+
+    assert!(registry.authenticate("alice", "wrong-password").is_err());
+}
@@ -1,12 +1,28 @@
-use daily_update_service::cache_manager::CacheManager;
+use daily_update_service::cache_manager::{CacheManager, CanExpire, EvictionCause, EvictionPolicy};
 use anyhow::Result;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A fresh, isolated directory for a `CacheManager`'s sled-backed durable
+/// tier, so concurrently-running tests don't contend over the same
+/// on-disk database.
+fn temp_cache_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("daily-update-cache-test-{}-{}", std::process::id(), n));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
 
 #[tokio::test]
 async fn test_cache_manager_initialization() {
     let cache_manager = CacheManager::new();
     
-    let init_result = cache_manager.initialize().await;
+    let cache_dir = temp_cache_dir();
+    let init_result = cache_manager.initialize(&cache_dir).await;
     assert!(init_result.is_ok());
     
     let stats = cache_manager.get_cache_stats().await;
@@ -18,7 +34,8 @@ async fn test_cache_manager_initialization() {
 #[tokio::test]
 async fn test_cache_insert_and_get() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Insert a value
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -37,7 +54,8 @@ async fn test_cache_insert_and_get() {
 #[tokio::test]
 async fn test_cache_miss() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Try to get a non-existent key
     let result = cache_manager.get("non-existent").await;
@@ -53,7 +71,8 @@ async fn test_cache_miss() {
 #[tokio::test]
 async fn test_cache_batch_insert() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     let entries = vec![
         ("key1".to_string(), "value1".to_string()),
@@ -76,7 +95,8 @@ async fn test_cache_batch_insert() {
 #[tokio::test]
 async fn test_cache_remove() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Insert a value
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -119,7 +139,8 @@ async fn test_cache_admin_operations() {
 #[tokio::test]
 async fn test_cache_daily_reset() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Insert some data
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -143,7 +164,8 @@ async fn test_cache_daily_reset() {
 #[tokio::test]
 async fn test_cache_concurrent_access() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     let cache_clone1 = cache_manager.clone();
     let cache_clone2 = cache_manager.clone();
@@ -171,7 +193,8 @@ async fn test_cache_concurrent_access() {
 #[tokio::test]
 async fn test_cache_stats_accuracy() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Insert some data
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -191,3 +214,433 @@ async fn test_cache_stats_accuracy() {
     assert_eq!(stats.hit_count, 2);
     assert_eq!(stats.miss_count, 2);
 }
+
+#[tokio::test]
+async fn test_memory_usage_bytes_reflects_key_and_value_lengths() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.insert("key2".to_string(), "value2".to_string()).await;
+
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.memory_usage_bytes, ("key1".len() + "value1".len() + "key2".len() + "value2".len()) as u64);
+}
+
+#[tokio::test]
+async fn test_memory_usage_bytes_accounts_for_replaced_and_evicted_entries() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "short".to_string()).await;
+    cache_manager.insert("key1".to_string(), "a much longer value".to_string()).await;
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.memory_usage_bytes, ("key1".len() + "a much longer value".len()) as u64);
+
+    cache_manager.remove("key1").await;
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.memory_usage_bytes, 0);
+}
+
+#[tokio::test]
+async fn test_with_memory_budget_evicts_once_byte_budget_is_exceeded() {
+    let cache_manager = Arc::new(CacheManager::new().with_memory_budget(20, 3600));
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.get("key1").await;
+    cache_manager.insert("key2".to_string(), "value2".to_string()).await;
+    cache_manager.insert("key3".to_string(), "value3".to_string()).await;
+
+    let stats = cache_manager.get_cache_stats().await;
+    assert!(stats.memory_usage_bytes <= 20, "memory_usage_bytes {} exceeded the 20-byte budget", stats.memory_usage_bytes);
+    assert_eq!(cache_manager.get("key1").await, None);
+}
+
+#[tokio::test]
+async fn test_get_or_load_returns_cached_value_without_running_loader() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+    cache_manager.insert("key1".to_string(), "cached".to_string()).await;
+
+    let result = cache_manager
+        .get_or_load("key1".to_string(), |_| async { panic!("loader should not run on a hit") })
+        .await
+        .unwrap();
+
+    assert_eq!(result, Some("cached".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_or_load_runs_loader_on_miss_and_caches_result() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let result = cache_manager
+        .get_or_load("key1".to_string(), |key| async move { Ok(Some(format!("loaded-{}", key))) })
+        .await
+        .unwrap();
+
+    assert_eq!(result, Some("loaded-key1".to_string()));
+    assert_eq!(cache_manager.get("key1").await, Some("loaded-key1".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_or_load_does_not_cache_not_found() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let result = cache_manager
+        .get_or_load("missing".to_string(), |_| async { Ok(None) })
+        .await
+        .unwrap();
+
+    assert_eq!(result, None);
+    assert_eq!(cache_manager.get("missing").await, None);
+}
+
+#[tokio::test]
+async fn test_get_or_load_propagates_loader_error_without_caching() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let result = cache_manager
+        .get_or_load("key1".to_string(), |_| async { Err::<Option<String>, _>(anyhow::anyhow!("boom")) })
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(cache_manager.get("key1").await, None);
+}
+
+#[tokio::test]
+async fn test_get_or_load_single_flights_concurrent_misses() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+    let load_count = Arc::new(std::sync::atomic::AtomicU64::new(0));
+
+    let mut handles = Vec::new();
+    for _ in 0..8 {
+        let cache_manager = cache_manager.clone();
+        let load_count = load_count.clone();
+        handles.push(tokio::spawn(async move {
+            cache_manager
+                .get_or_load("shared-key".to_string(), move |key| async move {
+                    load_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                    Ok(Some(format!("loaded-{}", key)))
+                })
+                .await
+        }));
+    }
+
+    for handle in handles {
+        assert_eq!(handle.await.unwrap().unwrap(), Some("loaded-shared-key".to_string()));
+    }
+
+    assert_eq!(load_count.load(std::sync::atomic::Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn test_cache_enforces_max_capacity_with_lru_policy() {
+    let cache_manager = Arc::new(CacheManager::new().with_max_capacity(2));
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.insert("key2".to_string(), "value2".to_string()).await;
+    // Touch key1 so key2, not key1, is the least-recently-used entry.
+    cache_manager.get("key1").await;
+    cache_manager.insert("key3".to_string(), "value3".to_string()).await;
+
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.total_entries, 2);
+    assert_eq!(stats.eviction_count, 1);
+    assert_eq!(cache_manager.get("key2").await, None);
+    assert_eq!(cache_manager.get("key1").await, Some("value1".to_string()));
+    assert_eq!(cache_manager.get("key3").await, Some("value3".to_string()));
+}
+
+#[tokio::test]
+async fn test_cache_enforces_max_capacity_with_lfu_policy() {
+    let cache_manager =
+        Arc::new(CacheManager::new().with_max_capacity(2).with_eviction_policy(EvictionPolicy::Lfu));
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.insert("key2".to_string(), "value2".to_string()).await;
+    // Access key1 repeatedly so key2 is the least-frequently-used entry,
+    // despite key1 also being the least-recently-used one.
+    cache_manager.get("key1").await;
+    cache_manager.get("key1").await;
+    cache_manager.insert("key3".to_string(), "value3".to_string()).await;
+
+    assert_eq!(cache_manager.get("key2").await, None);
+    assert_eq!(cache_manager.get("key1").await, Some("value1".to_string()));
+}
+
+#[tokio::test]
+async fn test_cache_eviction_listener_receives_capacity_cause() {
+    let cache_manager = Arc::new(CacheManager::new().with_max_capacity(1));
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let observed: Arc<Mutex<Vec<(String, String, EvictionCause)>>> = Arc::new(Mutex::new(Vec::new()));
+    let observed_clone = observed.clone();
+    cache_manager
+        .register_eviction_listener(move |key, value, cause| {
+            observed_clone.lock().unwrap().push((key, value, cause));
+        })
+        .await;
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.insert("key2".to_string(), "value2".to_string()).await;
+
+    let observed = observed.lock().unwrap();
+    assert_eq!(observed.len(), 1);
+    assert_eq!(observed[0], ("key1".to_string(), "value1".to_string(), EvictionCause::Capacity));
+}
+
+#[tokio::test]
+async fn test_cache_remove_notifies_explicit_cause() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let observed: Arc<Mutex<Vec<EvictionCause>>> = Arc::new(Mutex::new(Vec::new()));
+    let observed_clone = observed.clone();
+    cache_manager
+        .register_eviction_listener(move |_key, _value, cause| {
+            observed_clone.lock().unwrap().push(cause);
+        })
+        .await;
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.remove("key1").await;
+
+    assert_eq!(*observed.lock().unwrap(), vec![EvictionCause::Explicit]);
+}
+
+#[tokio::test]
+async fn test_cache_insert_over_existing_key_notifies_replaced_cause() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let observed: Arc<Mutex<Vec<EvictionCause>>> = Arc::new(Mutex::new(Vec::new()));
+    let observed_clone = observed.clone();
+    cache_manager
+        .register_eviction_listener(move |_key, _value, cause| {
+            observed_clone.lock().unwrap().push(cause);
+        })
+        .await;
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.insert("key1".to_string(), "value2".to_string()).await;
+
+    assert_eq!(*observed.lock().unwrap(), vec![EvictionCause::Replaced]);
+    // Overwriting an existing key doesn't change the entry count.
+    assert_eq!(cache_manager.get_cache_stats().await.total_entries, 1);
+}
+
+#[tokio::test]
+async fn test_insert_with_ttl_expires_entry_on_get() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager
+        .insert_with_ttl("key1".to_string(), "value1".to_string(), Duration::from_millis(10))
+        .await;
+    assert_eq!(cache_manager.get("key1").await, Some("value1".to_string()));
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(cache_manager.get("key1").await, None);
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.total_entries, 0);
+    assert_eq!(stats.expired_count, 1);
+}
+
+#[tokio::test]
+async fn test_get_treats_idle_entry_as_expired() {
+    let cache_manager = Arc::new(CacheManager::new().with_idle_seconds(0));
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(cache_manager.get("key1").await, None);
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExpiringProduct {
+    deadline_passed: bool,
+}
+
+impl CanExpire for ExpiringProduct {
+    fn is_expired(&self) -> bool {
+        self.deadline_passed
+    }
+}
+
+#[tokio::test]
+async fn test_get_checked_expires_value_that_reports_itself_expired() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let product = ExpiringProduct { deadline_passed: true };
+    cache_manager.insert("key1".to_string(), serde_json::to_string(&product).unwrap()).await;
+
+    let result = cache_manager.get_checked::<ExpiringProduct>("key1").await;
+    assert_eq!(result, None);
+    assert_eq!(cache_manager.get_cache_stats().await.total_entries, 0);
+}
+
+#[tokio::test]
+async fn test_get_checked_keeps_value_that_is_not_yet_expired() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let product = ExpiringProduct { deadline_passed: false };
+    let serialized = serde_json::to_string(&product).unwrap();
+    cache_manager.insert("key1".to_string(), serialized.clone()).await;
+
+    let result = cache_manager.get_checked::<ExpiringProduct>("key1").await;
+    assert_eq!(result, Some(serialized));
+}
+
+#[tokio::test]
+async fn test_get_warms_from_backing_after_simulated_restart() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+
+    // Simulate a restart: wipe the hot tier, but the backing tier (on disk)
+    // is untouched.
+    cache_manager.clear().await;
+    assert_eq!(cache_manager.get_cache_stats().await.total_entries, 0);
+
+    let result = cache_manager.get("key1").await;
+    assert_eq!(result, Some("value1".to_string()));
+
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.backing_hit_count, 1);
+    assert_eq!(stats.total_entries, 1); // the hot tier was warmed
+}
+
+#[tokio::test]
+async fn test_get_counts_backing_miss_when_key_absent_from_both_tiers() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    let result = cache_manager.get("does-not-exist").await;
+    assert_eq!(result, None);
+
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.backing_miss_count, 1);
+    assert_eq!(stats.miss_count, 1);
+}
+
+#[tokio::test]
+async fn test_perform_daily_cache_reset_preserves_backing_tier() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.perform_daily_cache_reset().await.unwrap();
+
+    assert_eq!(cache_manager.get_cache_stats().await.total_entries, 0);
+    assert_eq!(cache_manager.get("key1").await, Some("value1".to_string()));
+}
+
+#[tokio::test]
+async fn test_clear_backing_discards_durable_tier() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.clear().await;
+    cache_manager.clear_backing().await.unwrap();
+
+    assert_eq!(cache_manager.get("key1").await, None);
+}
+
+#[tokio::test]
+async fn test_remove_deletes_from_backing_tier() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.remove("key1").await;
+
+    assert_eq!(cache_manager.get("key1").await, None);
+    assert_eq!(cache_manager.get_cache_stats().await.backing_miss_count, 1);
+}
+
+#[tokio::test]
+async fn test_snapshot_and_restore_round_trips_entries_and_stats() {
+    let original = Arc::new(CacheManager::new());
+    original.initialize(&temp_cache_dir()).await.unwrap();
+
+    original.insert("key1".to_string(), "value1".to_string()).await;
+    original.insert("key2".to_string(), "value2".to_string()).await;
+    original.insert_admin("admin-key".to_string(), "admin-value".to_string());
+    // Generate some non-zero stats to round-trip alongside the entries.
+    original.get("key1").await;
+    original.get("does-not-exist").await;
+
+    let snapshot_path = temp_cache_dir().join("snapshot.postcard");
+    original.snapshot_to(&snapshot_path).await.unwrap();
+    let original_stats = original.get_cache_stats().await;
+
+    let restored = Arc::new(CacheManager::new());
+    restored.initialize(&temp_cache_dir()).await.unwrap();
+    restored.restore_from(&snapshot_path).await.unwrap();
+
+    let restored_stats = restored.get_cache_stats().await;
+    assert_eq!(restored_stats.hit_count, original_stats.hit_count);
+    assert_eq!(restored_stats.miss_count, original_stats.miss_count);
+
+    assert_eq!(restored.get("key1").await, Some("value1".to_string()));
+    assert_eq!(restored.get("key2").await, Some("value2".to_string()));
+    assert_eq!(restored.get_admin("admin-key"), Some("admin-value".to_string()));
+}
+
+#[tokio::test]
+async fn test_sync_makes_stats_immediately_consistent_after_reset() {
+    let cache_manager = Arc::new(CacheManager::new());
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
+
+    cache_manager.insert("key1".to_string(), "value1".to_string()).await;
+    cache_manager.insert("key2".to_string(), "value2".to_string()).await;
+    cache_manager.perform_daily_cache_reset().await.unwrap();
+
+    // No sleep: `perform_daily_cache_reset` already calls `sync` internally,
+    // so moka's deferred housekeeping is guaranteed flushed by the time this
+    // returns.
+    let stats = cache_manager.get_cache_stats().await;
+    assert_eq!(stats.total_entries, 0);
+    assert_eq!(stats.eviction_count, 0);
+
+    // Calling it again directly is a harmless no-op once already flushed.
+    cache_manager.sync().await;
+}
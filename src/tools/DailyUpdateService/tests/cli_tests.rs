@@ -0,0 +1,58 @@
+use chrono::Duration as ChronoDuration;
+use chrono::Utc;
+use daily_update_service::cli::{format_relative, Cli};
+use daily_update_service::config::ServiceConfig;
+
+#[test]
+fn test_format_relative_just_now() {
+    assert_eq!(format_relative(Utc::now()), "just now");
+}
+
+#[test]
+fn test_format_relative_minutes_and_hours() {
+    assert_eq!(format_relative(Utc::now() - ChronoDuration::minutes(3)), "3 minutes ago");
+    assert_eq!(format_relative(Utc::now() - ChronoDuration::hours(1)), "1 hour ago");
+    assert_eq!(format_relative(Utc::now() - ChronoDuration::hours(5)), "5 hours ago");
+}
+
+#[test]
+fn test_format_relative_days() {
+    assert_eq!(format_relative(Utc::now() - ChronoDuration::days(2)), "2 days ago");
+}
+
+#[test]
+fn test_apply_interval_override_feeds_update_config() {
+    let cli = Cli {
+        interval: Some("12h".to_string()),
+        command: None,
+    };
+    let mut config = ServiceConfig::default();
+    cli.apply_interval_override(&mut config).unwrap();
+
+    assert_eq!(config.update_config.update_interval_hours, 12);
+    assert_eq!(config.update_config.check_interval_minutes, 12 * 60);
+}
+
+#[test]
+fn test_apply_interval_override_noop_when_unset() {
+    let cli = Cli {
+        interval: None,
+        command: None,
+    };
+    let mut config = ServiceConfig::default();
+    let before = config.update_config.update_interval_hours;
+    cli.apply_interval_override(&mut config).unwrap();
+
+    assert_eq!(config.update_config.update_interval_hours, before);
+}
+
+#[test]
+fn test_apply_interval_override_rejects_invalid_interval() {
+    let cli = Cli {
+        interval: Some("not-a-duration".to_string()),
+        command: None,
+    };
+    let mut config = ServiceConfig::default();
+
+    assert!(cli.apply_interval_override(&mut config).is_err());
+}
@@ -2,12 +2,24 @@ use daily_update_service::cache_manager::CacheManager;
 use anyhow::Result;
 use std::sync::Arc;
 
+/// A fresh, isolated directory for a `CacheManager`'s sled-backed durable
+/// tier, so concurrently-running tests don't contend over the same
+/// on-disk database.
+fn temp_cache_dir() -> std::path::PathBuf {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let n = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("daily-update-cache-test-{}-{}", std::process::id(), n));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
 #[tokio::test]
 async fn test_cache_manager_all_branches() {
     let cache_manager = Arc::new(CacheManager::new());
     
     // Test initialization
-    let init_result = cache_manager.initialize().await;
+    let cache_dir = temp_cache_dir();
+    let init_result = cache_manager.initialize(&cache_dir).await;
     assert!(init_result.is_ok());
     assert_eq!(init_result.unwrap(), true);
     
@@ -22,7 +34,8 @@ async fn test_cache_manager_all_branches() {
 #[tokio::test]
 async fn test_cache_manager_insert_and_get_branches() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Test inserting new key
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -50,7 +63,8 @@ async fn test_cache_manager_insert_and_get_branches() {
 #[tokio::test]
 async fn test_cache_manager_batch_operations_loops() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Test batch insert with 0 entries (empty loop)
     cache_manager.insert_batch(vec![]).await;
@@ -84,7 +98,8 @@ async fn test_cache_manager_batch_operations_loops() {
 #[tokio::test]
 async fn test_cache_manager_remove_branches() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Test removing non-existent key
     cache_manager.remove("non-existent").await;
@@ -132,7 +147,8 @@ async fn test_cache_manager_admin_cache_branches() {
 #[tokio::test]
 async fn test_cache_manager_daily_reset_branches() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Add some data
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -167,7 +183,8 @@ async fn test_cache_manager_daily_reset_branches() {
 #[tokio::test]
 async fn test_cache_manager_concurrent_operations() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     let cache_clone1 = cache_manager.clone();
     let cache_clone2 = cache_manager.clone();
@@ -201,7 +218,8 @@ async fn test_cache_manager_concurrent_operations() {
 #[tokio::test]
 async fn test_cache_manager_concurrent_reads_and_writes() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Insert initial data
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -236,7 +254,8 @@ async fn test_cache_manager_concurrent_reads_and_writes() {
 #[tokio::test]
 async fn test_cache_manager_edge_cases() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Test with empty strings
     cache_manager.insert("".to_string(), "".to_string()).await;
@@ -264,7 +283,8 @@ async fn test_cache_manager_edge_cases() {
 #[tokio::test]
 async fn test_cache_manager_stats_accuracy() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Test initial stats
     let stats = cache_manager.get_cache_stats().await;
@@ -295,7 +315,8 @@ async fn test_cache_manager_stats_accuracy() {
 #[tokio::test]
 async fn test_cache_manager_clear_operations() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Add some data
     cache_manager.insert("key1".to_string(), "value1".to_string()).await;
@@ -322,7 +343,8 @@ async fn test_cache_manager_clear_operations() {
 #[tokio::test]
 async fn test_cache_manager_multiple_resets() {
     let cache_manager = Arc::new(CacheManager::new());
-    cache_manager.initialize().await.unwrap();
+    let cache_dir = temp_cache_dir();
+    cache_manager.initialize(&cache_dir).await.unwrap();
     
     // Add data and reset multiple times
     for i in 0..5 {
@@ -61,7 +61,7 @@ async fn test_go_integration_operations_when_uninitialized() {
     let migrate_result = go_integration.migrate_product(&test_product).await;
     assert!(migrate_result.is_err(), "migrate_product should fail when uninitialized");
     
-    let execute_result = go_integration.execute_go_command("test").await;
+    let execute_result = go_integration.execute_go_command(&["test"]).await;
     assert!(execute_result.is_err(), "execute_go_command should fail when uninitialized");
     
     let verify_result = go_integration.verify_go_component().await;
@@ -306,21 +306,30 @@ async fn test_go_integration_edge_cases() {
 async fn test_go_integration_command_execution_edge_cases() {
     let go_integration = GoIntegration::new();
     
-    // Test with empty command
-    let result = go_integration.execute_go_command("").await;
+    // Test with no arguments
+    let result = go_integration.execute_go_command(&[]).await;
     assert!(result.is_err());
-    
-    // Test with whitespace-only command
-    let result = go_integration.execute_go_command("   ").await;
+
+    // Test with an argument that would have been mis-split by the old
+    // whitespace-splitting implementation
+    let result = go_integration.execute_go_command(&["test command with spaces"]).await;
     assert!(result.is_err());
-    
-    // Test with command containing special characters
-    let result = go_integration.execute_go_command("test command with spaces").await;
+
+    // Test with many arguments
+    let long_args: Vec<&str> = std::iter::repeat("test").take(1000).collect();
+    let result = go_integration.execute_go_command(&long_args).await;
     assert!(result.is_err());
-    
-    // Test with very long command
-    let long_command = "test ".repeat(1000);
-    let result = go_integration.execute_go_command(&long_command).await;
+}
+
+#[tokio::test]
+async fn test_execute_go_with_stdin_fails_without_binary() {
+    let go_integration = GoIntegration::new();
+
+    // No Go binary is present in this test environment, so spawning should
+    // fail before the JSON payload is even written to stdin.
+    let result = go_integration
+        .execute_go_with_stdin(&["migrate-product"], "{\"slug\":\"test\"}")
+        .await;
     assert!(result.is_err());
 }
 
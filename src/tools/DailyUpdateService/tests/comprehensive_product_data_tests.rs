@@ -1,5 +1,6 @@
 use daily_update_service::ProductData;
 use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_product_data_creation_branches() {
@@ -17,7 +18,7 @@ async fn test_product_data_creation_branches() {
     assert_eq!(product.submitted_by, "test-user-id");
     
     // Test default values
-    assert_eq!(product.id, None);
+    assert_ne!(product.id, Uuid::nil()); // a fresh id is generated at construction
     assert_eq!(product.brand_id, None);
     assert_eq!(product.image_url, None);
     assert_eq!(product.description, None);
@@ -177,8 +178,8 @@ async fn test_product_data_optional_fields() {
     );
     
     // Test setting optional fields
-    product.id = Some(123);
-    product.brand_id = Some(456);
+    let brand_id = Uuid::now_v7();
+    product.brand_id = Some(brand_id);
     product.image_url = Some("https://example.com/image.jpg".to_string());
     product.description = Some("A great protein powder".to_string());
     product.servings_per_container = Some(30);
@@ -194,8 +195,7 @@ async fn test_product_data_optional_fields() {
     product.reviewed_at = Some(Utc::now());
     
     // Verify all fields are set
-    assert_eq!(product.id, Some(123));
-    assert_eq!(product.brand_id, Some(456));
+    assert_eq!(product.brand_id, Some(brand_id));
     assert_eq!(product.image_url, Some("https://example.com/image.jpg".to_string()));
     assert_eq!(product.description, Some("A great protein powder".to_string()));
     assert_eq!(product.servings_per_container, Some(30));
@@ -211,7 +211,6 @@ async fn test_product_data_optional_fields() {
     assert!(product.reviewed_at.is_some());
     
     // Test clearing optional fields
-    product.id = None;
     product.brand_id = None;
     product.image_url = None;
     product.description = None;
@@ -222,7 +221,6 @@ async fn test_product_data_optional_fields() {
     product.reviewed_at = None;
     
     // Verify fields are cleared
-    assert_eq!(product.id, None);
     assert_eq!(product.brand_id, None);
     assert_eq!(product.image_url, None);
     assert_eq!(product.description, None);
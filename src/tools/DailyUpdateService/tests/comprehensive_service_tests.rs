@@ -5,6 +5,17 @@ use daily_update_service::{
 };
 use std::sync::Arc;
 
+/// A fresh, isolated directory for a `CacheManager`'s sled-backed durable
+/// tier, so concurrently-running tests don't contend over the same
+/// on-disk database.
+fn temp_cache_dir() -> std::path::PathBuf {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let n = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("daily-update-cache-test-{}-{}", std::process::id(), n));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
 #[tokio::test]
 async fn test_service_creation_and_initialization_branches() {
     let config = ServiceConfig::default();
@@ -198,12 +209,19 @@ async fn test_service_config_variations() {
             binary_path: std::path::PathBuf::from("custom-go-binary"),
             working_directory: std::path::PathBuf::from("custom-working-dir"),
             command_timeout: 60,
+            pool_max_size: 4,
         },
         update_config: daily_update_service::config::UpdateConfig {
             update_interval_hours: 2,
             check_interval_minutes: 10,
             enable_automatic_updates: false,
+            tranquility: 0,
+            max_batch_size: 25,
+            batch_concurrency: 4,
         },
+        database_url: None,
+        scraper_config: daily_update_service::config::ScraperConfig::default(),
+        auth_config: daily_update_service::config::AuthConfig::default(),
     };
     
     let service2 = DailyUpdateServiceV2::new(custom_config);
@@ -225,7 +243,8 @@ async fn test_service_component_interaction_branches() {
     assert_eq!(go_stats.is_initialized, false);
     
     // Test that components are properly initialized
-    let init_result = service.cache_manager.initialize().await;
+    let cache_dir = temp_cache_dir();
+    let init_result = service.cache_manager.initialize(&cache_dir).await;
     assert!(init_result.is_ok());
 }
 
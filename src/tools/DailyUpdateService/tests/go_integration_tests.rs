@@ -1,10 +1,34 @@
+use async_trait::async_trait;
 use daily_update_service::{
-    go_integration::GoIntegration,
+    go_integration::{GoIntegration, GoResponse},
+    transport::{GoTransport, HttpTransport, TransportOutput},
     ProductData,
 };
 use anyhow::Result;
 use std::sync::Arc;
 
+/// A `GoTransport` that fails every `migrate-products-batch` call (to
+/// exercise `flush_batch`'s re-queue-on-transient-failure path) but
+/// otherwise succeeds, so `initialize`'s `verify` call still passes.
+struct FlakyBatchTransport;
+
+#[async_trait]
+impl GoTransport for FlakyBatchTransport {
+    async fn call(&self, command: &str, _json_payload: Option<String>) -> Result<TransportOutput> {
+        if command == "migrate-products-batch" {
+            return Err(anyhow::anyhow!("simulated transient Go failure"));
+        }
+        Ok(TransportOutput {
+            exit_code: 0,
+            body: "{\"success\": true}".to_string(),
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "flaky-batch"
+    }
+}
+
 #[tokio::test]
 async fn test_go_integration_creation() {
     let go_integration = GoIntegration::new();
@@ -39,6 +63,17 @@ async fn test_go_integration_uninitialized_operations() {
     
     let migrate_result = go_integration.migrate_product(&test_product).await;
     assert!(migrate_result.is_err());
+
+    let enqueue_result = go_integration.enqueue(test_product).await;
+    assert!(enqueue_result.is_err());
+}
+
+#[tokio::test]
+async fn test_flush_is_a_noop_on_an_empty_buffer() {
+    let go_integration = GoIntegration::new();
+
+    // Nothing was ever enqueued, so this must not attempt a transport call.
+    assert!(go_integration.flush().await.is_ok());
 }
 
 #[tokio::test]
@@ -51,6 +86,9 @@ async fn test_go_integration_stats() {
     assert_eq!(stats.is_initialized, false);
     assert_eq!(stats.go_binary_path, "");
     assert_eq!(stats.working_directory, "");
+    assert_eq!(stats.transport, "subprocess");
+    assert_eq!(stats.last_scheduled_run, None);
+    assert_eq!(stats.consecutive_failures, 0);
 }
 
 #[tokio::test]
@@ -58,7 +96,7 @@ async fn test_go_integration_command_execution() {
     let go_integration = GoIntegration::new();
     
     // Test command execution (should fail without Go binary)
-    let result = go_integration.execute_go_command("test-command").await;
+    let result = go_integration.execute_go_command(&["test-command"]).await;
     assert!(result.is_err());
 }
 
@@ -134,12 +172,12 @@ async fn test_go_integration_response_parsing() {
     let success_response = "{\"success\": true, \"message\": \"Operation completed\"}";
     let success_result = go_integration.parse_go_response(success_response);
     assert!(success_result);
-    
-    // Test failure response parsing (contains "success" so should return true)
+
+    // Test failure response parsing (success: false must now decode as false)
     let failure_response = "{\"success\": false, \"message\": \"Operation failed\"}";
     let failure_result = go_integration.parse_go_response(failure_response);
-    assert!(failure_result); // Contains "success" so returns true
-    
+    assert!(!failure_result);
+
     // Test invalid JSON
     let invalid_response = "invalid json";
     let invalid_result = go_integration.parse_go_response(invalid_response);
@@ -221,9 +259,203 @@ async fn test_go_integration_command_execution_with_binary() {
     let go_integration = GoIntegration::new();
     
     // Test command execution with real Go binary
-    let result = go_integration.execute_go_command("help").await;
+    let result = go_integration.execute_go_command(&["help"]).await;
     if result.is_ok() {
         assert_eq!(result.unwrap(), 0); // Success exit code
     }
 }
 
+#[tokio::test]
+async fn test_go_response_decode_success() {
+    let response = GoResponse::decode("{\"success\": true, \"data\": {\"id\": 1}}").unwrap();
+    assert!(response.success);
+    assert!(response.error.is_none());
+    assert!(response.data.is_some());
+}
+
+#[tokio::test]
+async fn test_go_response_decode_explicit_failure() {
+    let response =
+        GoResponse::decode("{\"success\": false, \"error\": \"duplicate slug\"}").unwrap();
+    assert!(!response.success);
+    assert_eq!(response.error.as_deref(), Some("duplicate slug"));
+}
+
+#[tokio::test]
+async fn test_go_response_decode_rejects_non_object() {
+    assert!(GoResponse::decode("[1, 2, 3]").is_err());
+    assert!(GoResponse::decode("\"just a string\"").is_err());
+}
+
+#[tokio::test]
+async fn test_go_response_decode_rejects_malformed_json() {
+    assert!(GoResponse::decode("").is_err());
+    assert!(GoResponse::decode("{\"success\":}").is_err());
+    assert!(GoResponse::decode("not json at all").is_err());
+}
+
+#[tokio::test]
+async fn test_go_response_decode_ignores_leading_log_noise() {
+    let response = GoResponse::decode(
+        "2026-07-29T12:00:00Z INFO starting migration\n{\"success\": true, \"data\": {\"id\": 1}}",
+    )
+    .unwrap();
+    assert!(response.success);
+}
+
+#[tokio::test]
+async fn test_go_response_get_path_query() {
+    let response = GoResponse::decode(
+        "{\"success\": true, \"data\": {\"products\": [{\"name\": \"Whey\"}, {\"name\": \"Creatine\"}]}}",
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.get("data.products.#.name"),
+        serde_json::json!(["Whey", "Creatine"])
+    );
+    assert_eq!(response.get("data.products.#"), serde_json::json!(2));
+    assert_eq!(response.get("data.products.0.name"), serde_json::json!("Whey"));
+}
+
+#[tokio::test]
+async fn test_go_response_get_missing_path_is_null() {
+    let response = GoResponse::decode("{\"success\": true}").unwrap();
+
+    assert!(response.get("data.products.#.name").is_null());
+    assert!(response.get("nonexistent").is_null());
+}
+
+#[tokio::test]
+async fn test_go_integration_with_http_transport_reports_transport_name() {
+    let go_integration =
+        GoIntegration::new_with_transport(Arc::new(HttpTransport::new("http://127.0.0.1:0".to_string())));
+
+    let stats = go_integration.get_go_stats().await;
+    assert_eq!(stats.transport, "http");
+    assert!(!stats.is_initialized);
+}
+
+#[tokio::test]
+async fn test_migrate_products_batch_empty_slice_returns_empty_report() {
+    let go_integration = GoIntegration::new();
+    let report = go_integration.migrate_products_batch(&[], 4).await;
+
+    assert!(report.succeeded.is_empty());
+    assert!(report.failed.is_empty());
+}
+
+#[tokio::test]
+async fn test_migrate_products_batch_reports_per_item_failure_when_uninitialized() {
+    let go_integration = GoIntegration::new();
+    let products = vec![
+        ProductData::new("Product 1".to_string(), "product-1".to_string(), "protein".to_string(), "user-1".to_string()),
+        ProductData::new("Product 2".to_string(), "product-2".to_string(), "preworkout".to_string(), "user-2".to_string()),
+    ];
+
+    // concurrency == 0 should be treated as 1, not panic or divide by zero.
+    let report = go_integration.migrate_products_batch(&products, 0).await;
+
+    assert!(report.succeeded.is_empty());
+    assert_eq!(report.failed.len(), 2);
+    let slugs: Vec<&str> = report.failed.iter().map(|(slug, _)| slug.as_str()).collect();
+    assert!(slugs.contains(&"product-1"));
+    assert!(slugs.contains(&"product-2"));
+}
+
+#[tokio::test]
+async fn test_go_integration_http_transport_uninitialized_operations_fail() {
+    let go_integration =
+        GoIntegration::new_with_transport(Arc::new(HttpTransport::new("http://127.0.0.1:0".to_string())));
+
+    // Even with an HTTP transport configured, operations must still be
+    // gated behind `initialize()`.
+    let result = go_integration.get_accepted_products().await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_http_transport_with_token_reports_same_name_as_unauthenticated() {
+    let go_integration = GoIntegration::new_with_transport(Arc::new(HttpTransport::with_token(
+        "http://127.0.0.1:0".to_string(),
+        "secret-token".to_string(),
+    )));
+
+    let stats = go_integration.get_go_stats().await;
+    assert_eq!(stats.transport, "http");
+}
+
+#[tokio::test]
+async fn test_go_stats_live_events_connected_is_none_without_live_events() {
+    let go_integration = GoIntegration::new();
+
+    let stats = go_integration.get_go_stats().await;
+    assert_eq!(stats.live_events_connected, None);
+}
+
+#[tokio::test]
+async fn test_go_stats_live_events_connected_is_some_false_before_initialize() {
+    let go_integration = GoIntegration::new().with_live_events("ws://127.0.0.1:1/events", None);
+
+    // The bridge hasn't connected yet (and `initialize` was never called),
+    // so this must report `Some(false)`, not `None`.
+    let stats = go_integration.get_go_stats().await;
+    assert_eq!(stats.live_events_connected, Some(false));
+}
+
+#[tokio::test]
+async fn test_buffered_count_reflects_unflushed_enqueue_only_after_initialize() {
+    let go_integration = GoIntegration::new();
+
+    // `enqueue` is gated behind `is_initialized`, same as every other
+    // operation, so the buffer stays empty here.
+    assert_eq!(go_integration.buffered_count().await, 0);
+}
+
+#[tokio::test]
+async fn test_last_flush_time_is_none_before_any_flush() {
+    let go_integration = GoIntegration::new();
+    assert_eq!(go_integration.last_flush_time().await, None);
+}
+
+#[tokio::test]
+async fn test_flush_requeues_buffer_on_transient_transport_failure() {
+    let mut go_integration = GoIntegration::new_with_transport(Arc::new(FlakyBatchTransport));
+    assert!(go_integration.initialize().await.unwrap());
+
+    let product = ProductData::new(
+        "Test Product".to_string(),
+        "test-product".to_string(),
+        "protein".to_string(),
+        "test-user-id".to_string(),
+    );
+    go_integration.enqueue(product).await.unwrap();
+    assert_eq!(go_integration.buffered_count().await, 1);
+
+    // The transport call fails transiently -- the product must be
+    // re-queued, not dropped, and `last_flush_time` must stay unset since
+    // the flush never actually completed.
+    go_integration.flush().await.unwrap();
+    assert_eq!(go_integration.buffered_count().await, 1);
+    assert_eq!(go_integration.last_flush_time().await, None);
+}
+
+#[tokio::test]
+async fn test_go_stats_pool_status_is_none_without_worker_pool() {
+    let go_integration = GoIntegration::new();
+
+    let stats = go_integration.get_go_stats().await;
+    assert_eq!(stats.pool_status, None);
+}
+
+#[tokio::test]
+async fn test_go_stats_pool_status_is_none_before_initialize_even_with_worker_pool() {
+    let go_integration =
+        GoIntegration::new().with_worker_pool(4, std::time::Duration::from_secs(30));
+
+    // The `Pool` itself is only built by `initialize` (it has to wrap the
+    // final transport), so before that this must still be `None`.
+    let stats = go_integration.get_go_stats().await;
+    assert_eq!(stats.pool_status, None);
+}
+
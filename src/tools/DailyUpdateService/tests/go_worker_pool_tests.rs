@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use daily_update_service::go_worker_pool::Pool;
+use daily_update_service::transport::{GoTransport, TransportOutput};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A `GoTransport` that always succeeds and counts how many calls were made,
+/// so pool tests don't depend on a real Go binary being present.
+struct FakeTransport {
+    calls: AtomicUsize,
+}
+
+impl FakeTransport {
+    fn new() -> Self {
+        Self {
+            calls: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl GoTransport for FakeTransport {
+    async fn call(&self, _command: &str, _json_payload: Option<String>) -> anyhow::Result<TransportOutput> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        Ok(TransportOutput {
+            exit_code: 0,
+            body: "{\"success\": true}".to_string(),
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "fake"
+    }
+}
+
+#[tokio::test]
+async fn test_pool_status_reports_max_size_and_full_availability_when_idle() {
+    let pool = Pool::new(Arc::new(FakeTransport::new()), 4);
+
+    let status = pool.status();
+    assert_eq!(status.size, 4);
+    assert_eq!(status.available, 4);
+    assert_eq!(status.waiting, 0);
+}
+
+#[tokio::test]
+async fn test_pool_max_size_zero_is_treated_as_one() {
+    let pool = Pool::new(Arc::new(FakeTransport::new()), 0);
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn test_pool_get_reduces_available_until_worker_is_dropped() {
+    let pool = Pool::new(Arc::new(FakeTransport::new()), 2);
+
+    let worker = pool.get(Duration::from_secs(1)).await.unwrap();
+    assert_eq!(pool.status().available, 1);
+
+    drop(worker);
+    // Dropping a `PooledWorker` returns its worker via a spawned task, so
+    // give the scheduler a moment to run it before asserting.
+    tokio::task::yield_now().await;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(pool.status().available, 2);
+}
+
+#[tokio::test]
+async fn test_pool_get_times_out_when_saturated() {
+    let pool = Pool::new(Arc::new(FakeTransport::new()), 1);
+
+    let _worker = pool.get(Duration::from_secs(1)).await.unwrap();
+
+    let result = pool.get(Duration::from_millis(50)).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_pool_worker_call_reaches_underlying_transport() {
+    let transport = Arc::new(FakeTransport::new());
+    let pool = Pool::new(transport.clone(), 1);
+
+    let worker = pool.get(Duration::from_secs(1)).await.unwrap();
+    let output = worker.call("verify", None).await.unwrap();
+
+    assert_eq!(output.exit_code, 0);
+    assert_eq!(transport.calls.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn test_pool_get_saturates_at_max_size_under_concurrent_load() {
+    let pool = Arc::new(Pool::new(Arc::new(FakeTransport::new()), 3));
+
+    // Hold 3 checkouts open concurrently -- the pool must be fully
+    // saturated (0 available) while they're all outstanding.
+    let mut held = Vec::new();
+    for _ in 0..3 {
+        held.push(pool.get(Duration::from_secs(1)).await.unwrap());
+    }
+
+    assert_eq!(pool.status().available, 0);
+
+    // A 4th checkout must block until one of the 3 is released.
+    let pool_clone = pool.clone();
+    let waiter = tokio::spawn(async move { pool_clone.get(Duration::from_secs(5)).await });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(pool.status().waiting, 1);
+
+    held.pop();
+    let result = waiter.await.unwrap();
+    assert!(result.is_ok());
+}
@@ -5,6 +5,17 @@ use daily_update_service::{
 };
 use std::sync::Arc;
 
+/// A fresh, isolated directory for a `CacheManager`'s sled-backed durable
+/// tier, so concurrently-running tests don't contend over the same
+/// on-disk database.
+fn temp_cache_dir() -> std::path::PathBuf {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let n = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("daily-update-cache-test-{}-{}", std::process::id(), n));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
 #[tokio::test]
 async fn test_service_creation() {
     let config = ServiceConfig::default();
@@ -87,7 +98,8 @@ async fn test_cache_manager_operations() {
     let cache_manager = Arc::new(CacheManager::new());
     
     // Initialize cache manager
-    let init_result = cache_manager.initialize().await;
+    let cache_dir = temp_cache_dir();
+    let init_result = cache_manager.initialize(&cache_dir).await;
     assert!(init_result.is_ok());
     
     // Test cache operations
@@ -120,6 +132,8 @@ async fn test_service_stats() {
     assert_eq!(stats.total_processed, 0);
     assert_eq!(stats.total_accepted, 0);
     assert_eq!(stats.total_denied, 0);
+    // No workers registered yet (the service hasn't been started).
+    assert_eq!(stats.average_worker_occupancy, 0.0);
 }
 
 #[tokio::test]
@@ -171,9 +185,12 @@ async fn test_service_config_default() {
     assert_eq!(config.cache_config.ttl_seconds, 3600);
     assert_eq!(config.cache_config.idle_seconds, 1800);
     assert_eq!(config.go_config.command_timeout, 30);
+    assert_eq!(config.go_config.pool_max_size, 4);
     assert_eq!(config.update_config.update_interval_hours, 1);
     assert_eq!(config.update_config.check_interval_minutes, 5);
     assert!(config.update_config.enable_automatic_updates);
+    assert_eq!(config.update_config.max_batch_size, 25);
+    assert_eq!(config.update_config.batch_concurrency, 4);
 }
 
 #[tokio::test]
@@ -290,3 +307,49 @@ async fn test_go_integration_with_binary() {
     // This might fail if the product doesn't exist in the database
     assert!(migrate_result.is_ok() || migrate_result.is_err());
 }
+
+#[tokio::test]
+async fn test_pause_updates_errors_before_start() {
+    let config = ServiceConfig::default();
+    let service = DailyUpdateServiceV2::new(config);
+
+    // The control channel doesn't exist until the update worker is started.
+    assert!(service.pause_updates().await.is_err());
+    assert!(service.resume_updates().await.is_err());
+    assert!(service.trigger_update_now().await.is_err());
+    assert!(service.set_tranquility(5).await.is_err());
+}
+
+#[tokio::test]
+async fn test_service_stats_reports_default_update_control_state() {
+    let config = ServiceConfig::default();
+    let service = DailyUpdateServiceV2::new(config);
+
+    let stats = service.get_service_stats().await;
+    assert!(!stats.update_control.paused);
+    assert_eq!(stats.update_control.tranquility, 0);
+}
+
+#[tokio::test]
+async fn test_pause_and_resume_updates_after_start() {
+    let config = ServiceConfig::default();
+    let service = DailyUpdateServiceV2::new(config);
+
+    service.start().await.unwrap();
+
+    service.pause_updates().await.unwrap();
+    // Give the worker's supervised loop a moment to drain the command --
+    // comfortably more than one `UPDATE_CHECK_INTERVAL` (250ms).
+    tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+    assert!(service.get_service_stats().await.update_control.paused);
+
+    service.resume_updates().await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+    assert!(!service.get_service_stats().await.update_control.paused);
+
+    service.set_tranquility(3).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+    assert_eq!(service.get_service_stats().await.update_control.tranquility, 3);
+
+    service.stop().await.unwrap();
+}
@@ -0,0 +1,54 @@
+use daily_update_service::migrations::MigrationRunner;
+use std::fs;
+
+#[tokio::test]
+async fn test_discover_migrations_orders_by_version() {
+    let dir = std::env::temp_dir().join(format!("daily-update-migrations-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    fs::write(dir.join("V2__add_flavor.sql"), "ALTER TABLE products ADD COLUMN flavor TEXT;").unwrap();
+    fs::write(dir.join("V1__init.sql"), "CREATE TABLE products (id BIGINT PRIMARY KEY);").unwrap();
+    fs::write(dir.join("README.md"), "not a migration").unwrap();
+
+    let runner = MigrationRunner::new(&dir, "postgres://localhost/test");
+    let files = runner.discover_migrations().unwrap();
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].version, 1);
+    assert_eq!(files[0].name, "init");
+    assert_eq!(files[1].version, 2);
+    assert_eq!(files[1].name, "add_flavor");
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_discover_migrations_rejects_bad_filenames() {
+    let dir = std::env::temp_dir().join(format!("daily-update-migrations-bad-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    fs::write(dir.join("not-a-migration.sql"), "SELECT 1;").unwrap();
+
+    let runner = MigrationRunner::new(&dir, "postgres://localhost/test");
+    let result = runner.discover_migrations();
+    assert!(result.is_err());
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_discover_migrations_checksum_changes_with_content() {
+    let dir = std::env::temp_dir().join(format!("daily-update-migrations-crc-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    fs::write(dir.join("V1__init.sql"), "CREATE TABLE a (id BIGINT);").unwrap();
+    let runner = MigrationRunner::new(&dir, "postgres://localhost/test");
+    let checksum_before = runner.discover_migrations().unwrap()[0].checksum;
+
+    fs::write(dir.join("V1__init.sql"), "CREATE TABLE a (id BIGINT, name TEXT);").unwrap();
+    let checksum_after = runner.discover_migrations().unwrap()[0].checksum;
+
+    assert_ne!(checksum_before, checksum_after);
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,87 @@
+use daily_update_service::pending_queue::{PendingUpdateQueue, UpdateOutcome};
+use daily_update_service::ProductData;
+use std::fs;
+
+fn make_product(slug: &str) -> ProductData {
+    ProductData::new(
+        "Test Product".to_string(),
+        slug.to_string(),
+        "protein".to_string(),
+        "test-user-id".to_string(),
+    )
+}
+
+#[tokio::test]
+async fn test_enqueue_assigns_monotonic_update_ids_in_order() {
+    let dir = std::env::temp_dir().join(format!("daily-update-pending-queue-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let queue = PendingUpdateQueue::open(&dir).unwrap();
+
+    let first = queue.enqueue_if_absent(&make_product("first")).unwrap().unwrap();
+    let second = queue.enqueue_if_absent(&make_product("second")).unwrap().unwrap();
+    assert!(second > first);
+
+    let pending = queue.pending().unwrap();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].0, first);
+    assert_eq!(pending[1].0, second);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_enqueue_if_absent_skips_already_pending_slug() {
+    let dir = std::env::temp_dir().join(format!("daily-update-pending-queue-dup-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let queue = PendingUpdateQueue::open(&dir).unwrap();
+
+    let first = queue.enqueue_if_absent(&make_product("dup")).unwrap();
+    let second = queue.enqueue_if_absent(&make_product("dup")).unwrap();
+
+    assert!(first.is_some());
+    assert!(second.is_none());
+    assert_eq!(queue.pending().unwrap().len(), 1);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_finalize_removes_from_pending_and_records_outcome() {
+    let dir = std::env::temp_dir().join(format!("daily-update-pending-queue-finalize-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let queue = PendingUpdateQueue::open(&dir).unwrap();
+
+    let update_id = queue.enqueue_if_absent(&make_product("finalize-me")).unwrap().unwrap();
+    assert_eq!(queue.pending_depth(), 1);
+
+    queue.finalize(update_id, UpdateOutcome::Accepted).unwrap();
+
+    assert_eq!(queue.pending_depth(), 0);
+    assert_eq!(queue.highest_processed_id(), Some(update_id));
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_reopen_replays_unfinalized_entries() {
+    let dir = std::env::temp_dir().join(format!("daily-update-pending-queue-reopen-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let update_id = {
+        let queue = PendingUpdateQueue::open(&dir).unwrap();
+        queue.enqueue_if_absent(&make_product("survivor")).unwrap().unwrap()
+    };
+
+    // Simulates a restart after a crash before the entry was finalized.
+    let queue = PendingUpdateQueue::open(&dir).unwrap();
+    let pending = queue.pending().unwrap();
+
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].0, update_id);
+    assert_eq!(pending[0].1.slug, "survivor");
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,17 @@
+use daily_update_service::queue::MigrationQueue;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_connect_fails_against_unreachable_redis() {
+    // No Redis broker is available in this test environment; connecting
+    // should surface an error rather than hang or panic.
+    let result = MigrationQueue::connect(
+        "redis://127.0.0.1:1/",
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    )
+    .await;
+
+    assert!(result.is_err());
+}
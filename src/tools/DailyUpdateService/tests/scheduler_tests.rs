@@ -0,0 +1,88 @@
+use daily_update_service::scheduler::{parse_interval, Scheduler};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn test_parse_interval_single_unit() {
+    assert_eq!(parse_interval("30s").unwrap(), Duration::from_secs(30));
+    assert_eq!(parse_interval("30m").unwrap(), Duration::from_secs(30 * 60));
+    assert_eq!(parse_interval("24h").unwrap(), Duration::from_secs(24 * 3600));
+    assert_eq!(parse_interval("2d").unwrap(), Duration::from_secs(2 * 86400));
+}
+
+#[test]
+fn test_parse_interval_combined_form() {
+    assert_eq!(
+        parse_interval("1h30m").unwrap(),
+        Duration::from_secs(3600 + 30 * 60)
+    );
+}
+
+#[test]
+fn test_parse_interval_rejects_empty_and_invalid() {
+    assert!(parse_interval("").is_err());
+    assert!(parse_interval("   ").is_err());
+    assert!(parse_interval("-30m").is_err());
+    assert!(parse_interval("30x").is_err());
+    assert!(parse_interval("h").is_err());
+}
+
+#[tokio::test]
+async fn test_scheduler_clamps_to_minimum_tick() {
+    let scheduler = Scheduler::new(Duration::from_millis(1));
+    // A zero-length interval would busy-loop; this just asserts
+    // construction with a sub-minimum interval doesn't panic and that the
+    // scheduler starts with no run history.
+    assert_eq!(scheduler.last_run().await, None);
+    assert_eq!(scheduler.consecutive_failures(), 0);
+}
+
+#[tokio::test]
+async fn test_scheduler_runs_job_and_records_last_run() {
+    let scheduler = Arc::new(Scheduler::new(Duration::from_millis(10)));
+    let scheduler_clone = scheduler.clone();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+    let runs = Arc::new(AtomicU32::new(0));
+    let runs_clone = runs.clone();
+
+    let handle = tokio::spawn(async move {
+        scheduler_clone
+            .run_until(
+                || {
+                    runs_clone.fetch_add(1, Ordering::Relaxed);
+                    async { Ok(()) }
+                },
+                shutdown_rx,
+            )
+            .await;
+    });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let _ = shutdown_tx.send(());
+    handle.await.unwrap();
+
+    assert!(runs.load(Ordering::Relaxed) > 0);
+    assert!(scheduler.last_run().await.is_some());
+    assert_eq!(scheduler.consecutive_failures(), 0);
+}
+
+#[tokio::test]
+async fn test_scheduler_tracks_consecutive_failures() {
+    let scheduler = Arc::new(Scheduler::new(Duration::from_millis(10)));
+    let scheduler_clone = scheduler.clone();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel();
+
+    let handle = tokio::spawn(async move {
+        scheduler_clone
+            .run_until(|| async { Err(anyhow::anyhow!("simulated failure")) }, shutdown_rx)
+            .await;
+    });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let _ = shutdown_tx.send(());
+    handle.await.unwrap();
+
+    assert!(scheduler.consecutive_failures() > 0);
+    assert!(scheduler.last_run().await.is_none());
+}
@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use daily_update_service::auth::Reviewer;
+use daily_update_service::db::ProductStore;
+use daily_update_service::scraper::{synthetic_submitter, RetailerScraper, ScraperRunner};
+use daily_update_service::ProductData;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// In-memory `ProductStore` fake, keyed by slug.
+#[derive(Default)]
+struct FakeProductStore {
+    products: Mutex<Vec<ProductData>>,
+}
+
+#[async_trait]
+impl ProductStore for FakeProductStore {
+    async fn insert(&self, product: &ProductData) -> anyhow::Result<()> {
+        self.products.lock().unwrap().push(product.clone());
+        Ok(())
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> anyhow::Result<Option<ProductData>> {
+        Ok(self
+            .products
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|p| p.slug == slug)
+            .cloned())
+    }
+
+    async fn list_pending(&self) -> anyhow::Result<Vec<ProductData>> {
+        Ok(self.products.lock().unwrap().clone())
+    }
+
+    async fn product_id_exists(&self, id: &Uuid) -> anyhow::Result<bool> {
+        Ok(self.products.lock().unwrap().iter().any(|p| &p.id == id))
+    }
+
+    async fn approve(&self, slug: &str, reviewer: &Reviewer) -> anyhow::Result<()> {
+        let mut products = self.products.lock().unwrap();
+        let product = products
+            .iter_mut()
+            .find(|p| p.slug == slug)
+            .ok_or_else(|| anyhow::anyhow!("no product with slug '{}'", slug))?;
+        product.approval_status = 1;
+        product.reviewed_by = Some(reviewer.id.to_string());
+        Ok(())
+    }
+
+    async fn reject(&self, slug: &str, reviewer: &Reviewer, rejection_reason: &str) -> anyhow::Result<()> {
+        let mut products = self.products.lock().unwrap();
+        let product = products
+            .iter_mut()
+            .find(|p| p.slug == slug)
+            .ok_or_else(|| anyhow::anyhow!("no product with slug '{}'", slug))?;
+        product.approval_status = -1;
+        product.reviewed_by = Some(reviewer.id.to_string());
+        product.rejection_reason = Some(rejection_reason.to_string());
+        Ok(())
+    }
+}
+
+/// `RetailerScraper` fake returning a fixed catalog, or an error if configured to fail.
+struct FakeScraper {
+    source: String,
+    catalog: Vec<ProductData>,
+    fails: bool,
+}
+
+#[async_trait]
+impl RetailerScraper for FakeScraper {
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    async fn fetch_catalog(&self) -> anyhow::Result<Vec<ProductData>> {
+        if self.fails {
+            return Err(anyhow::anyhow!("simulated fetch failure"));
+        }
+        Ok(self.catalog.clone())
+    }
+}
+
+fn product(name: &str, slug: &str) -> ProductData {
+    ProductData::new(name.to_string(), slug.to_string(), "protein".to_string(), "scraper:test".to_string())
+}
+
+#[test]
+fn test_synthetic_submitter_format() {
+    assert_eq!(synthetic_submitter("example-store"), "scraper:example-store");
+}
+
+#[tokio::test]
+async fn test_run_once_inserts_new_products() {
+    let store = Arc::new(FakeProductStore::default());
+    let scraper = Arc::new(FakeScraper {
+        source: "example-store".to_string(),
+        catalog: vec![product("Whey Protein", "whey-protein"), product("Creatine", "creatine")],
+        fails: false,
+    });
+
+    let runner = ScraperRunner::new(vec![scraper], store.clone());
+    let inserted = runner.run_once().await.unwrap();
+
+    assert_eq!(inserted, 2);
+    assert_eq!(store.list_pending().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_run_once_skips_already_known_slug() {
+    let store = Arc::new(FakeProductStore::default());
+    store.insert(&product("Whey Protein", "whey-protein")).await.unwrap();
+
+    let scraper = Arc::new(FakeScraper {
+        source: "example-store".to_string(),
+        catalog: vec![product("Whey Protein", "whey-protein"), product("Creatine", "creatine")],
+        fails: false,
+    });
+
+    let runner = ScraperRunner::new(vec![scraper], store.clone());
+    let inserted = runner.run_once().await.unwrap();
+
+    // Only "creatine" is new; "whey-protein" was already in the store.
+    assert_eq!(inserted, 1);
+    assert_eq!(store.list_pending().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_run_once_continues_after_one_scraper_fails() {
+    let store = Arc::new(FakeProductStore::default());
+    let failing = Arc::new(FakeScraper {
+        source: "flaky-store".to_string(),
+        catalog: vec![],
+        fails: true,
+    });
+    let working = Arc::new(FakeScraper {
+        source: "example-store".to_string(),
+        catalog: vec![product("Creatine", "creatine")],
+        fails: false,
+    });
+
+    let runner = ScraperRunner::new(vec![failing, working], store.clone());
+    let inserted = runner.run_once().await.unwrap();
+
+    assert_eq!(inserted, 1);
+}
@@ -0,0 +1,164 @@
+use anyhow::Result;
+use daily_update_service::db::ProductStore;
+use daily_update_service::migrations::MigrationRunner;
+use std::path::PathBuf;
+use std::sync::Arc;
+use uuid::Uuid;
+
+mod store;
+pub use store::PortableProductStore;
+
+/// Which real backend a `TestDb` ended up provisioning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    Postgres,
+    Sqlite,
+}
+
+/// Per-test ephemeral database. Provisions an isolated Postgres database (via
+/// `CREATE DATABASE` against the `DATABASE_URL` connection from the
+/// environment) if `DATABASE_URL` is set, or an embedded SQLite file
+/// otherwise; runs the full migration set against it; and hands back a
+/// connected `ProductStore`. Cleaned up best-effort on `Drop`.
+pub struct TestDb {
+    pub store: Arc<dyn ProductStore>,
+    pub backend: Backend,
+    cleanup: Option<Cleanup>,
+}
+
+enum Cleanup {
+    Postgres { admin_url: String, database_name: String },
+    Sqlite { path: PathBuf },
+}
+
+impl TestDb {
+    /// Provision a fresh, migrated database: a throwaway Postgres database
+    /// when `DATABASE_URL` is set in the environment, an embedded SQLite
+    /// file otherwise.
+    pub async fn connect() -> Result<Self> {
+        match std::env::var("DATABASE_URL") {
+            Ok(base_url) if !base_url.is_empty() => Self::connect_postgres(&base_url).await,
+            _ => Self::connect_sqlite().await,
+        }
+    }
+
+    async fn connect_postgres(base_url: &str) -> Result<Self> {
+        let database_name = format!("daily_update_test_{}", Uuid::now_v7().simple());
+
+        let admin_pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(base_url)
+            .await?;
+        sqlx::query(&format!("CREATE DATABASE \"{}\"", database_name))
+            .execute(&admin_pool)
+            .await?;
+        admin_pool.close().await;
+
+        let database_url = rewrite_database_name(base_url, &database_name)?;
+        let store = migrate_and_connect(&database_url).await?;
+
+        Ok(Self {
+            store,
+            backend: Backend::Postgres,
+            cleanup: Some(Cleanup::Postgres {
+                admin_url: base_url.to_string(),
+                database_name,
+            }),
+        })
+    }
+
+    async fn connect_sqlite() -> Result<Self> {
+        let path = std::env::temp_dir().join(format!("daily_update_test_{}.sqlite3", Uuid::now_v7()));
+        let database_url = format!("sqlite://{}?mode=rwc", path.display());
+
+        let store = migrate_and_connect(&database_url).await?;
+
+        Ok(Self {
+            store,
+            backend: Backend::Sqlite,
+            cleanup: Some(Cleanup::Sqlite { path }),
+        })
+    }
+}
+
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        match self.cleanup.take() {
+            Some(Cleanup::Postgres { admin_url, database_name }) => {
+                tokio::spawn(async move {
+                    if let Ok(admin_pool) = sqlx::postgres::PgPoolOptions::new()
+                        .max_connections(1)
+                        .connect(&admin_url)
+                        .await
+                    {
+                        let _ = sqlx::query(&format!(
+                            "DROP DATABASE IF EXISTS \"{}\" WITH (FORCE)",
+                            database_name
+                        ))
+                        .execute(&admin_pool)
+                        .await;
+                    }
+                });
+            }
+            Some(Cleanup::Sqlite { path }) => {
+                let _ = std::fs::remove_file(&path);
+            }
+            None => {}
+        }
+    }
+}
+
+fn rewrite_database_name(base_url: &str, database_name: &str) -> Result<String> {
+    let mut url = url::Url::parse(base_url)?;
+    url.set_path(&format!("/{}", database_name));
+    Ok(url.to_string())
+}
+
+/// Run the harness's portable schema migration against `database_url`, then
+/// connect a `PortableProductStore` to it.
+async fn migrate_and_connect(database_url: &str) -> Result<Arc<dyn ProductStore>> {
+    let migrations_dir = write_schema_fixture()?;
+    MigrationRunner::new(&migrations_dir, database_url.to_string())
+        .run()
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    std::fs::remove_dir_all(&migrations_dir).ok();
+
+    let pool = sqlx::AnyPool::connect(database_url).await?;
+    Ok(Arc::new(PortableProductStore::new(pool)))
+}
+
+/// Write the harness's portable (Postgres- and SQLite-compatible) `products`
+/// schema as a single migration file into a fresh temp directory, the same
+/// way `migration_tests.rs` fabricates fixture files for `MigrationRunner`.
+fn write_schema_fixture() -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("daily-update-test-migrations-{}", Uuid::now_v7()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(
+        dir.join("V1__products.sql"),
+        "CREATE TABLE products (\
+            id TEXT PRIMARY KEY, \
+            brand_id TEXT, \
+            category TEXT NOT NULL, \
+            name TEXT NOT NULL, \
+            slug TEXT NOT NULL UNIQUE, \
+            image_url TEXT, \
+            description TEXT, \
+            servings_per_container INTEGER, \
+            serving_size_g DOUBLE PRECISION, \
+            transparency_score INTEGER, \
+            confidence_level TEXT, \
+            dosage_rating INTEGER, \
+            danger_rating INTEGER, \
+            community_rating DOUBLE PRECISION, \
+            total_reviews INTEGER, \
+            approval_status INTEGER NOT NULL, \
+            submitted_by TEXT NOT NULL, \
+            reviewed_by TEXT, \
+            rejection_reason TEXT, \
+            created_at TEXT NOT NULL, \
+            updated_at TEXT NOT NULL, \
+            reviewed_at TEXT)",
+    )?;
+    Ok(dir)
+}
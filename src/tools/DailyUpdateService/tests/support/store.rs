@@ -0,0 +1,174 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use daily_update_service::auth::Reviewer;
+use daily_update_service::db::ProductStore;
+use daily_update_service::ProductData;
+use sqlx::any::{AnyPool, AnyRow};
+use sqlx::Row;
+use uuid::Uuid;
+
+/// `ProductStore` backed by `sqlx::Any`, storing every column as TEXT so the
+/// same SQL runs unchanged against Postgres or SQLite. Used only by the
+/// `TestDb` harness -- the production service always uses
+/// `daily_update_service::db::PostgresProductStore`.
+pub struct PortableProductStore {
+    pool: AnyPool,
+}
+
+impl PortableProductStore {
+    pub fn new(pool: AnyPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn to_rfc3339(time: DateTime<Utc>) -> String {
+    time.to_rfc3339()
+}
+
+fn parse_dt(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .expect("stored timestamp is valid rfc3339")
+        .with_timezone(&Utc)
+}
+
+#[async_trait]
+impl ProductStore for PortableProductStore {
+    async fn insert(&self, product: &ProductData) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO products (
+                id, brand_id, category, name, slug, image_url, description,
+                servings_per_container, serving_size_g, transparency_score,
+                confidence_level, dosage_rating, danger_rating,
+                community_rating, total_reviews, approval_status,
+                submitted_by, reviewed_by, rejection_reason,
+                created_at, updated_at, reviewed_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(product.id.to_string())
+        .bind(product.brand_id.map(|id| id.to_string()))
+        .bind(&product.category)
+        .bind(&product.name)
+        .bind(&product.slug)
+        .bind(&product.image_url)
+        .bind(&product.description)
+        .bind(product.servings_per_container)
+        .bind(product.serving_size_g)
+        .bind(product.transparency_score)
+        .bind(&product.confidence_level)
+        .bind(product.dosage_rating)
+        .bind(product.danger_rating)
+        .bind(product.community_rating)
+        .bind(product.total_reviews)
+        .bind(product.approval_status)
+        .bind(&product.submitted_by)
+        .bind(&product.reviewed_by)
+        .bind(&product.rejection_reason)
+        .bind(to_rfc3339(product.created_at))
+        .bind(to_rfc3339(product.updated_at))
+        .bind(product.reviewed_at.map(to_rfc3339))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_slug(&self, slug: &str) -> Result<Option<ProductData>> {
+        let row = sqlx::query("SELECT * FROM products WHERE slug = ?")
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.as_ref().map(row_to_product))
+    }
+
+    async fn list_pending(&self) -> Result<Vec<ProductData>> {
+        let rows = sqlx::query("SELECT * FROM products WHERE approval_status = 0")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.iter().map(row_to_product).collect())
+    }
+
+    async fn product_id_exists(&self, id: &Uuid) -> Result<bool> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM products WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_one(&self.pool)
+            .await?;
+        let count: i64 = row.try_get("count")?;
+
+        Ok(count > 0)
+    }
+
+    async fn approve(&self, slug: &str, reviewer: &Reviewer) -> Result<()> {
+        let now = to_rfc3339(Utc::now());
+        sqlx::query(
+            "UPDATE products
+             SET approval_status = 1, reviewed_by = ?, reviewed_at = ?, updated_at = ?
+             WHERE slug = ?",
+        )
+        .bind(reviewer.id.to_string())
+        .bind(&now)
+        .bind(&now)
+        .bind(slug)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reject(&self, slug: &str, reviewer: &Reviewer, rejection_reason: &str) -> Result<()> {
+        if rejection_reason.trim().is_empty() {
+            return Err(anyhow::anyhow!("rejection_reason must not be empty"));
+        }
+
+        let now = to_rfc3339(Utc::now());
+        sqlx::query(
+            "UPDATE products
+             SET approval_status = -1, reviewed_by = ?, rejection_reason = ?, reviewed_at = ?, updated_at = ?
+             WHERE slug = ?",
+        )
+        .bind(reviewer.id.to_string())
+        .bind(rejection_reason)
+        .bind(&now)
+        .bind(&now)
+        .bind(slug)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_product(row: &AnyRow) -> ProductData {
+    let id: String = row.get("id");
+    let brand_id: Option<String> = row.get("brand_id");
+    let created_at: String = row.get("created_at");
+    let updated_at: String = row.get("updated_at");
+    let reviewed_at: Option<String> = row.get("reviewed_at");
+
+    ProductData {
+        id: Uuid::parse_str(&id).expect("stored id is a valid uuid"),
+        brand_id: brand_id.map(|s| Uuid::parse_str(&s).expect("stored brand_id is a valid uuid")),
+        category: row.get("category"),
+        name: row.get("name"),
+        slug: row.get("slug"),
+        image_url: row.get("image_url"),
+        description: row.get("description"),
+        servings_per_container: row.get("servings_per_container"),
+        serving_size_g: row.get("serving_size_g"),
+        transparency_score: row.get("transparency_score"),
+        confidence_level: row.get("confidence_level"),
+        dosage_rating: row.get("dosage_rating"),
+        danger_rating: row.get("danger_rating"),
+        community_rating: row.get("community_rating"),
+        total_reviews: row.get("total_reviews"),
+        approval_status: row.get("approval_status"),
+        submitted_by: row.get("submitted_by"),
+        reviewed_by: row.get("reviewed_by"),
+        rejection_reason: row.get("rejection_reason"),
+        created_at: parse_dt(&created_at),
+        updated_at: parse_dt(&updated_at),
+        reviewed_at: reviewed_at.map(|s| parse_dt(&s)),
+    }
+}
@@ -0,0 +1,36 @@
+use daily_update_service::watcher::ProductSubmission;
+use daily_update_service::ProductData;
+
+fn submission(name: &str, slug: &str, category: &str, flavor: &str, year: &str) -> ProductSubmission {
+    ProductSubmission {
+        product: ProductData::new(name.to_string(), slug.to_string(), category.to_string(), "user-1".to_string()),
+        flavor: Some(flavor.to_string()),
+        year: Some(year.to_string()),
+    }
+}
+
+#[test]
+fn test_content_hash_ignores_whitespace_and_case() {
+    let a = submission("Protein Powder", "protein-powder", "protein", "Vanilla", "2024");
+    let b = submission("  protein powder  ", "protein-powder", "PROTEIN", "vanilla", "2024");
+
+    assert_eq!(a.content_hash(), b.content_hash());
+}
+
+#[test]
+fn test_content_hash_differs_on_meaningful_change() {
+    let a = submission("Protein Powder", "protein-powder", "protein", "Vanilla", "2024");
+    let b = submission("Protein Powder", "protein-powder", "protein", "Chocolate", "2024");
+
+    assert_ne!(a.content_hash(), b.content_hash());
+}
+
+#[test]
+fn test_content_hash_tolerates_missing_flavor_and_year() {
+    let with_flavor = submission("Protein Powder", "protein-powder", "protein", "Vanilla", "2024");
+    let mut without_flavor = with_flavor.clone();
+    without_flavor.flavor = None;
+    without_flavor.year = None;
+
+    assert_ne!(with_flavor.content_hash(), without_flavor.content_hash());
+}
@@ -0,0 +1,123 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use daily_update_service::worker::{Worker, WorkerManager, WorkerRunState, WorkerState};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Runs a fixed number of `Active` steps, then reports `Done`.
+struct CountingWorker {
+    steps_remaining: u32,
+    ran: Arc<AtomicU32>,
+}
+
+#[async_trait]
+impl Worker for CountingWorker {
+    fn name(&self) -> &str {
+        "counting-worker"
+    }
+
+    async fn step(&mut self) -> Result<WorkerState> {
+        if self.steps_remaining == 0 {
+            return Ok(WorkerState::Done);
+        }
+        self.steps_remaining -= 1;
+        self.ran.fetch_add(1, Ordering::Relaxed);
+        Ok(WorkerState::Active)
+    }
+}
+
+/// Always reports an error, to exercise the supervisor's restart bookkeeping.
+struct FailingWorker;
+
+#[async_trait]
+impl Worker for FailingWorker {
+    fn name(&self) -> &str {
+        "failing-worker"
+    }
+
+    async fn step(&mut self) -> Result<WorkerState> {
+        Err(anyhow::anyhow!("simulated failure"))
+    }
+}
+
+#[tokio::test]
+async fn test_worker_manager_list_is_empty_before_any_registration() {
+    let manager = WorkerManager::new();
+    assert!(manager.list().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_worker_manager_runs_worker_to_completion() {
+    let manager = WorkerManager::new();
+    let ran = Arc::new(AtomicU32::new(0));
+
+    manager
+        .register(Box::new(CountingWorker {
+            steps_remaining: 3,
+            ran: ran.clone(),
+        }))
+        .await;
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(ran.load(Ordering::Relaxed), 3);
+
+    let infos = manager.list().await;
+    assert_eq!(infos.len(), 1);
+    assert_eq!(infos[0].name, "counting-worker");
+    assert_eq!(infos[0].state, WorkerRunState::Dead);
+    assert_eq!(infos[0].iterations, 3);
+    assert!(infos[0].last_error.is_none());
+    // All steps ran back-to-back with no idle sleep, so the worker was
+    // occupied for its entire trailing window.
+    assert_eq!(infos[0].occupancy_rate, 1.0);
+}
+
+/// Reports `Idle` with a long sleep on every step, to exercise the low end
+/// of the occupancy tracker.
+struct AlwaysIdleWorker;
+
+#[async_trait]
+impl Worker for AlwaysIdleWorker {
+    fn name(&self) -> &str {
+        "always-idle-worker"
+    }
+
+    async fn step(&mut self) -> Result<WorkerState> {
+        Ok(WorkerState::Idle(Duration::from_millis(200)))
+    }
+}
+
+#[tokio::test]
+async fn test_worker_manager_reports_low_occupancy_for_mostly_idle_worker() {
+    let manager = WorkerManager::new();
+
+    manager.register(Box::new(AlwaysIdleWorker)).await;
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+
+    let infos = manager.list().await;
+    assert_eq!(infos.len(), 1);
+    // The step itself is instantaneous and the sleep dominates, so occupancy
+    // should be close to (but not exactly) zero.
+    assert!(infos[0].occupancy_rate < 0.5);
+
+    manager.stop().await;
+}
+
+#[tokio::test]
+async fn test_worker_manager_records_last_error_after_failure() {
+    let manager = WorkerManager::new();
+
+    manager.register(Box::new(FailingWorker)).await;
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let infos = manager.list().await;
+    assert_eq!(infos.len(), 1);
+    assert_eq!(infos[0].state, WorkerRunState::Dead);
+    assert_eq!(infos[0].last_error.as_deref(), Some("simulated failure"));
+
+    manager.stop().await;
+}